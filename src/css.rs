@@ -0,0 +1,112 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers to serialize and parse CSS transform functions.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error returned when parsing a CSS transform function fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string is not a call to the expected transform function.
+    InvalidFunction,
+    /// The function was not given the expected number of arguments.
+    InvalidArgumentCount,
+    /// One of the arguments is not a valid number.
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ParseError::InvalidFunction => "invalid transform function",
+            ParseError::InvalidArgumentCount => "invalid number of arguments",
+            ParseError::InvalidNumber => "invalid number",
+        })
+    }
+}
+
+impl Error for ParseError {}
+
+/// Formats `name(a, b, ...)`.
+pub fn to_function_string<T: fmt::Display>(name: &str, args: &[T]) -> String {
+    let mut result = String::from(name);
+    result.push('(');
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            result.push_str(", ");
+        }
+        result.push_str(&arg.to_string());
+    }
+    result.push(')');
+    result
+}
+
+/// Parses `name(a, b, ...)` into `args`, which must have exactly as many elements as
+/// the function has arguments.
+///
+/// Whitespace is allowed around the function and its arguments. The function name
+/// is matched case-insensitively as in CSS.
+pub fn parse_function<T: FromStr>(s: &str, name: &str, args: &mut [T]) -> Result<(), ParseError> {
+    let s = s.trim();
+    if s.len() < name.len() || !s.is_char_boundary(name.len())
+        || !s[..name.len()].eq_ignore_ascii_case(name) {
+        return Err(ParseError::InvalidFunction);
+    }
+    let s = s[name.len()..].trim_start();
+    if !s.starts_with('(') || !s.ends_with(')') {
+        return Err(ParseError::InvalidFunction);
+    }
+    let s = &s[1..s.len() - 1];
+
+    let mut count = 0;
+    for arg in s.split(',') {
+        if count == args.len() {
+            return Err(ParseError::InvalidArgumentCount);
+        }
+        let arg = arg.trim();
+        if arg.is_empty() {
+            return Err(ParseError::InvalidNumber);
+        }
+        args[count] = try!(arg.parse().map_err(|_| ParseError::InvalidNumber));
+        count += 1;
+    }
+    if count != args.len() {
+        return Err(ParseError::InvalidArgumentCount);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_function() {
+        let mut args = [0.0f32; 3];
+        assert_eq!(parse_function(" foo( 1, -2.5,3e2 ) ", "foo", &mut args), Ok(()));
+        assert_eq!(args, [1.0, -2.5, 300.0]);
+        assert_eq!(parse_function("FOO(1,2,3)", "foo", &mut args), Ok(()));
+
+        assert_eq!(parse_function("bar(1, 2, 3)", "foo", &mut args), Err(ParseError::InvalidFunction));
+        assert_eq!(parse_function("foo 1, 2, 3", "foo", &mut args), Err(ParseError::InvalidFunction));
+        assert_eq!(parse_function("foo(1, 2)", "foo", &mut args), Err(ParseError::InvalidArgumentCount));
+        assert_eq!(parse_function("foo(1, 2, 3, 4)", "foo", &mut args), Err(ParseError::InvalidArgumentCount));
+        assert_eq!(parse_function("foo(1, x, 3)", "foo", &mut args), Err(ParseError::InvalidNumber));
+        assert_eq!(parse_function("foo(1, , 3)", "foo", &mut args), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_to_function_string() {
+        assert_eq!(to_function_string("foo", &[1.0, -2.5, 300.0]), "foo(1, -2.5, 300)");
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Easing functions producing interpolation factors.
+//!
+//! These helpers map a value to an interpolation factor between zero and one which can
+//! be passed to the various `lerp` methods to get a non-linear interpolation:
+//!
+//! ```rust
+//! use euclid::{point2, Point2D};
+//! use euclid::interpolate::smoothstep;
+//!
+//! let a: Point2D<f32> = point2(0.0, 0.0);
+//! let b = point2(10.0, 0.0);
+//! let eased = a.lerp(b, smoothstep(0.0, 1.0, 0.25));
+//! assert!(eased.x < 2.5);
+//! ```
+
+use num_traits::Float;
+
+/// Returns zero if `x` is smaller than `edge`, and one otherwise.
+#[inline]
+pub fn step<T: Float>(edge: T, x: T) -> T {
+    if x < edge { T::zero() } else { T::one() }
+}
+
+/// Performs a smooth Hermite interpolation between zero and one when `x` goes from
+/// `edge0` to `edge1`.
+///
+/// Returns zero if `x` is before `edge0` and one if it is past `edge1`. The
+/// derivative of the result is zero at both edges. This matches GLSL's `smoothstep`.
+#[inline]
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let zero = T::zero();
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+    let t = ((x - edge0) / (edge1 - edge0)).max(zero).min(one);
+    t * t * (three - two * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        assert_eq!(step(0.5, 0.2), 0.0);
+        assert_eq!(step(0.5, 0.5), 1.0);
+        assert_eq!(step(0.5, 0.7), 1.0);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+        assert_eq!(smoothstep(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+        assert_eq!(smoothstep(0.0, 1.0, 1.0), 1.0);
+        assert_eq!(smoothstep(0.0, 1.0, 2.0), 1.0);
+        assert_eq!(smoothstep(10.0, 20.0, 15.0), 0.5);
+        assert!(smoothstep(0.0, 1.0, 0.25) < 0.25);
+        assert!(smoothstep(0.0, 1.0, 0.75) > 0.75);
+    }
+}
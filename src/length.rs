@@ -56,7 +56,7 @@ impl<T, Unit> Serialize for Length<T, Unit> where T: Serialize {
 }
 
 impl<T, Unit> Length<T, Unit> {
-    pub fn new(x: T) -> Self {
+    pub const fn new(x: T) -> Self {
         Length(x, PhantomData)
     }
 }
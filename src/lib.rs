@@ -66,30 +66,45 @@ extern crate test;
 extern crate num_traits;
 
 pub use length::Length;
+pub use plane::Plane;
 pub use scale::TypedScale;
+pub use transform::Transform;
 pub use transform2d::{Transform2D, TypedTransform2D};
 pub use transform3d::{Transform3D, TypedTransform3D};
 pub use point::{
-    Point2D, TypedPoint2D, point2,
-    Point3D, TypedPoint3D, point3,
+    Point2D, TypedPoint2D, point2, cubic_bezier, catmull_rom, MeanPoint2D,
+    Point3D, TypedPoint3D, point3, cubic_bezier_3d, catmull_rom_3d,
 };
 pub use vector::{
-    Vector2D, TypedVector2D, vec2,
+    Vector2D, TypedVector2D, vec2, signed_area, is_clockwise, are_collinear,
     Vector3D, TypedVector3D, vec3,
+    Vector4D, TypedVector4D, vec4,
 };
 
-pub use rect::{Rect, TypedRect, rect};
+pub use rect::{
+    Rect, TypedRect, rect, smallest_containing_rect,
+    EdgeInclusion, HorizontalAlign, VerticalAlign,
+};
 pub use rotation::{TypedRotation2D, Rotation2D, TypedRotation3D, Rotation3D, Angle};
 pub use side_offsets::{SideOffsets2D, TypedSideOffsets2D};
 #[cfg(feature = "unstable")] pub use side_offsets::SideOffsets2DSimdI32;
 pub use size::{Size2D, TypedSize2D, size2};
+pub use translation::{TypedTranslation2D, Translation2D, TypedTranslation3D, Translation3D};
 pub use trig::Trig;
+pub use css::ParseError;
+pub use interpolate::{smoothstep, step};
+pub use query::{point_in_triangle, segment_intersection};
 
 pub mod approxeq;
+pub mod interpolate;
 pub mod num;
+pub mod query;
+mod css;
 mod length;
 #[macro_use]
 mod macros;
+mod plane;
+mod transform;
 mod transform2d;
 mod transform3d;
 mod point;
@@ -98,6 +113,7 @@ mod rotation;
 mod scale;
 mod side_offsets;
 mod size;
+mod translation;
 mod trig;
 mod vector;
 
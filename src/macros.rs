@@ -77,3 +77,117 @@ macro_rules! define_matrix {
         }
     )
 }
+
+macro_rules! impl_byte_conversions {
+    ($name:ident, $count:expr, $fields:tt) => (
+        impl_byte_conversions!(@impl $name, $count, $fields, u8, 1);
+        impl_byte_conversions!(@impl $name, $count, $fields, i8, 1);
+        impl_byte_conversions!(@impl $name, $count, $fields, u16, 2);
+        impl_byte_conversions!(@impl $name, $count, $fields, i16, 2);
+        impl_byte_conversions!(@impl $name, $count, $fields, u32, 4);
+        impl_byte_conversions!(@impl $name, $count, $fields, i32, 4);
+        impl_byte_conversions!(@impl $name, $count, $fields, u64, 8);
+        impl_byte_conversions!(@impl $name, $count, $fields, i64, 8);
+        impl_byte_conversions!(@impl $name, $count, $fields, f32, 4);
+        impl_byte_conversions!(@impl $name, $count, $fields, f64, 8);
+    );
+    (@impl $name:ident, $count:expr, { $($field:ident),+ }, $ty:ident, $size:expr) => (
+        impl<U> $name<$ty, U> {
+            /// Returns the memory representation of the components as a byte array
+            /// in little-endian byte order.
+            ///
+            /// The components are laid out one after the other in declaration order
+            /// (for example `x` then `y`), each of them taking `size_of::<T>()` bytes.
+            #[inline]
+            pub fn to_le_bytes(&self) -> [u8; $count * $size] {
+                let mut bytes = [0; $count * $size];
+                for (chunk, value) in bytes.chunks_mut($size).zip([$(self.$field),+].iter()) {
+                    chunk.copy_from_slice(&value.to_le_bytes());
+                }
+                bytes
+            }
+
+            /// Returns the memory representation of the components as a byte array
+            /// in native byte order.
+            ///
+            /// The layout is the same as `to_le_bytes`, save for the byte order.
+            #[inline]
+            pub fn to_ne_bytes(&self) -> [u8; $count * $size] {
+                let mut bytes = [0; $count * $size];
+                for (chunk, value) in bytes.chunks_mut($size).zip([$(self.$field),+].iter()) {
+                    chunk.copy_from_slice(&value.to_ne_bytes());
+                }
+                bytes
+            }
+
+            /// Creates a value from its representation as a byte array in little-endian
+            /// byte order, see `to_le_bytes` for the layout.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; $count * $size]) -> Self {
+                let mut chunks = bytes.chunks($size);
+                let mut next = || {
+                    let mut value = [0; $size];
+                    value.copy_from_slice(chunks.next().unwrap());
+                    $ty::from_le_bytes(value)
+                };
+                $(let $field = next();)+
+                $name::new($($field),+)
+            }
+
+            /// Creates a value from its representation as a byte array in native byte
+            /// order, see `to_ne_bytes` for the layout.
+            #[inline]
+            pub fn from_ne_bytes(bytes: [u8; $count * $size]) -> Self {
+                let mut chunks = bytes.chunks($size);
+                let mut next = || {
+                    let mut value = [0; $size];
+                    value.copy_from_slice(chunks.next().unwrap());
+                    $ty::from_ne_bytes(value)
+                };
+                $(let $field = next();)+
+                $name::new($($field),+)
+            }
+        }
+    );
+}
+
+macro_rules! impl_component_indexing {
+    ($name:ident, $count:expr, { $($index:expr => $field:ident),+ }) => (
+        /// Accesses the components by index, in declaration order (for example `0` for
+        /// `x` and `1` for `y`).
+        ///
+        /// Panics if the index is out of range.
+        impl<T, U> ::std::ops::Index<usize> for $name<T, U> {
+            type Output = T;
+            #[inline]
+            fn index(&self, index: usize) -> &T {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("index out of range: {} has {} components but the index is {}",
+                                stringify!($name), $count, index),
+                }
+            }
+        }
+
+        /// Mutably accesses the components by index, see the `Index` implementation.
+        impl<T, U> ::std::ops::IndexMut<usize> for $name<T, U> {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut T {
+                match index {
+                    $($index => &mut self.$field,)+
+                    _ => panic!("index out of range: {} has {} components but the index is {}",
+                                stringify!($name), $count, index),
+                }
+            }
+        }
+
+        impl<T: Copy, U> $name<T, U> {
+            /// Returns an iterator over the components, in the same order as the
+            /// indices of the `Index` implementation.
+            #[inline]
+            pub fn iter(&self) -> ::std::array::IntoIter<T, $count> {
+                IntoIterator::into_iter([$(self.$field),+])
+            }
+        }
+    );
+}
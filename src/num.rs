@@ -28,6 +28,20 @@ impl<T: num_traits::One> One for T {
 }
 
 
+/// Returns the smaller of two values, or the other value if one of them is NaN,
+/// which matches `Float::min` without requiring `Float`.
+#[inline]
+pub(crate) fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+/// Returns the larger of two values, or the other value if one of them is NaN,
+/// which matches `Float::max` without requiring `Float`.
+#[inline]
+pub(crate) fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
 pub trait Round : Copy { fn round(self) -> Self; }
 pub trait Floor : Copy { fn floor(self) -> Self; }
 pub trait Ceil : Copy { fn ceil(self) -> Self; }
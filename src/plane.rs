@@ -0,0 +1,107 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use point::TypedPoint3D;
+use vector::TypedVector3D;
+use num_traits::Float;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Sub};
+
+/// A plane in 3d space, defined by the points `p` for which `normal.dot(p) + d == 0`.
+///
+/// The normal points towards the positive half-space of the plane.
+pub struct Plane<T, U> {
+    pub normal: TypedVector3D<T, U>,
+    pub d: T,
+}
+
+impl<'de, T: Deserialize<'de>, U> Deserialize<'de> for Plane<T, U> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let (normal, d) = try!(Deserialize::deserialize(deserializer));
+        Ok(Plane::new(normal, d))
+    }
+}
+
+impl<T: Serialize, U> Serialize for Plane<T, U> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        (&self.normal, &self.d).serialize(serializer)
+    }
+}
+
+impl<T: Hash, U> Hash for Plane<T, U> {
+    fn hash<H: Hasher>(&self, h: &mut H) {
+        self.normal.hash(h);
+        self.d.hash(h);
+    }
+}
+
+impl<T: Clone, U> Clone for Plane<T, U> {
+    fn clone(&self) -> Self {
+        Plane {
+            normal: self.normal.clone(),
+            d: self.d.clone(),
+        }
+    }
+}
+
+impl<T: Copy, U> Copy for Plane<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Plane<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.normal == other.normal && self.d == other.d
+    }
+}
+
+impl<T: Eq, U> Eq for Plane<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for Plane<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Plane({:?}, {:?})", self.normal, self.d)
+    }
+}
+
+impl<T, U> Plane<T, U> {
+    /// Creates a plane from its normal and its distance term.
+    #[inline]
+    pub fn new(normal: TypedVector3D<T, U>, d: T) -> Self {
+        Plane { normal: normal, d: d }
+    }
+}
+
+impl<T: Copy + Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T>, U> Plane<T, U> {
+    /// Returns the signed distance between the given point and this plane, scaled
+    /// by the length of the normal.
+    ///
+    /// The distance is positive on the side the normal points to, and negative on
+    /// the other side.
+    #[inline]
+    pub fn signed_distance(&self, point: &TypedPoint3D<T, U>) -> T {
+        self.normal.dot(point.to_vector()) + self.d
+    }
+}
+
+impl<T: Float, U> Plane<T, U> {
+    /// Returns the same plane with a unit length normal, so that `signed_distance`
+    /// returns euclidean distances.
+    ///
+    /// The plane is returned unchanged if its normal is zero.
+    pub fn normalize(&self) -> Self {
+        let length = self.normal.dot(self.normal).sqrt();
+        if length == T::zero() {
+            return *self;
+        }
+        Plane::new(self.normal / length, self.d / length)
+    }
+}
@@ -7,7 +7,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::UnknownUnit;
+use super::{Angle, UnknownUnit};
 use approxeq::ApproxEq;
 use length::Length;
 use scale::TypedScale;
@@ -16,7 +16,7 @@ use num::*;
 use num_traits::{Float, NumCast};
 use vector::{TypedVector2D, TypedVector3D, vec2, vec3};
 use std::fmt;
-use std::ops::{Add, Mul, Sub, Div, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::ops::{Add, Mul, Sub, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::marker::PhantomData;
 
 define_matrix! {
@@ -66,7 +66,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedPoint2D<T, U> {
 impl<T: Copy, U> TypedPoint2D<T, U> {
     /// Constructor taking scalar values directly.
     #[inline]
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         TypedPoint2D { x: x, y: y, _unit: PhantomData }
     }
 
@@ -90,6 +90,14 @@ impl<T: Copy, U> TypedPoint2D<T, U> {
         vec2(self.x, self.y)
     }
 
+    /// Creates the point at the end of a vector starting at the origin.
+    ///
+    /// Equivalent to adding the vector to the origin. This is the inverse of `to_vector`.
+    #[inline]
+    pub fn from_vector(v: TypedVector2D<T, U>) -> Self {
+        point2(v.x, v.y)
+    }
+
     /// Swap x and y.
     #[inline]
     pub fn yx(&self) -> Self {
@@ -176,14 +184,106 @@ impl<T: Copy + Sub<T, Output=T>, U> Sub<TypedVector2D<T, U>> for TypedPoint2D<T,
 }
 
 impl<T: Float, U> TypedPoint2D<T, U> {
+    /// Returns this point rotated around the origin by the angle whose sine and cosine
+    /// are `sin` and `cos`, in the same direction as `TypedRotation2D`.
+    ///
+    /// This lets callers compute the trigonometry once when rotating many points by the
+    /// same angle, see also `rotate_slice`.
+    #[inline]
+    pub fn rotate_by_sin_cos(&self, sin: T, cos: T) -> Self {
+        point2(self.x * cos - self.y * sin, self.y * cos + self.x * sin)
+    }
+
+    /// Rotates all the points of a slice around the origin by `angle`, computing its
+    /// sine and cosine only once.
+    pub fn rotate_slice(points: &mut [Self], angle: Angle<T>) {
+        let (sin, cos) = angle.sin_cos();
+        for p in points {
+            *p = p.rotate_by_sin_cos(sin, cos);
+        }
+    }
+
+    /// Returns the same point with negative zero components replaced by positive zero,
+    /// so that values can be compared or hashed bitwise.
+    #[inline]
+    pub fn normalize_zero(&self) -> Self {
+        // Adding positive zero maps -0.0 to +0.0 and leaves other values unchanged.
+        let zero = T::zero();
+        point2(self.x + zero, self.y + zero)
+    }
+
+    /// Returns true if this point and `other` are equal up to a tolerance relative to
+    /// their magnitude: each component may differ by at most `rel_eps` times the
+    /// largest absolute value of the components of both points.
+    ///
+    /// Unlike `approx_eq`, this works at any scale, for example with coordinates in
+    /// the millions of pixels.
+    #[inline]
+    pub fn approx_eq_relative(&self, other: &Self, rel_eps: T) -> bool {
+        let scale = self.x.abs().max(self.y.abs()).max(other.x.abs()).max(other.y.abs());
+        let eps = rel_eps * scale;
+        (self.x - other.x).abs() <= eps && (self.y - other.y).abs() <= eps
+    }
+
+    /// Returns true if all of the components of this point are finite, that is
+    /// neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns true if all of the components of this point are within the
+    /// `ApproxEq` epsilon of zero.
+    #[inline]
+    pub fn approx_zero(&self) -> bool where T: ApproxEq<T> {
+        let zero = T::zero();
+        self.x.approx_eq(&zero) && self.y.approx_eq(&zero)
+    }
+}
+
+impl<T: Copy + PartialOrd, U> TypedPoint2D<T, U> {
+    /// Returns the component-wise minimum of this point and another one.
+    ///
+    /// Like `Float::min`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn min(self, other: Self) -> Self {
-         point2(self.x.min(other.x), self.y.min(other.y))
+        point2(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+        )
     }
 
+    /// Returns the component-wise maximum of this point and another one.
+    ///
+    /// Like `Float::max`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn max(self, other: Self) -> Self {
-        point2(self.x.max(other.x), self.y.max(other.y))
+        point2(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+        )
+    }
+
+    /// Returns the component-wise minimum and maximum of a set of points, computed
+    /// in a single pass.
+    ///
+    /// NaN components are ignored like in `min` and `max`. Returns `None` if the
+    /// slice is empty.
+    pub fn min_max(points: &[Self]) -> Option<(Self, Self)> {
+        let first = match points.first() {
+            Some(first) => *first,
+            None => return None,
+        };
+
+        let (mut min, mut max) = (first, first);
+        for point in &points[1..] {
+            min.x = partial_min(min.x, point.x);
+            max.x = partial_max(max.x, point.x);
+            min.y = partial_min(min.y, point.y);
+            max.y = partial_max(max.y, point.y);
+        }
+
+        Some((min, max))
     }
 }
 
@@ -372,6 +472,9 @@ impl<T: Copy, U> From<[T; 2]> for TypedPoint2D<T, U> {
     }
 }
 
+impl_byte_conversions!(TypedPoint2D, 2, {x, y});
+impl_component_indexing!(TypedPoint2D, 2, {0 => x, 1 => y});
+
 
 define_matrix! {
     /// A 3d Point tagged with a unit.
@@ -433,7 +536,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedPoint3D<T, U> {
 impl<T: Copy, U> TypedPoint3D<T, U> {
     /// Constructor taking scalar values directly.
     #[inline]
-    pub fn new(x: T, y: T, z: T) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         TypedPoint3D { x: x, y: y, z: z, _unit: PhantomData }
     }
 
@@ -451,6 +554,14 @@ impl<T: Copy, U> TypedPoint3D<T, U> {
         vec3(self.x, self.y, self.z)
     }
 
+    /// Creates the point at the end of a vector starting at the origin.
+    ///
+    /// Equivalent to adding the vector to the origin. This is the inverse of `to_vector`.
+    #[inline]
+    pub fn from_vector(v: TypedVector3D<T, U>) -> Self {
+        point3(v.x, v.y, v.z)
+    }
+
     /// Returns a 2d point using this point's x and y coordinates
     #[inline]
     pub fn xy(&self) -> TypedPoint2D<T, U> {
@@ -503,6 +614,17 @@ impl<T: Copy, U> TypedPoint3D<T, U> {
     }
 }
 
+impl<T: Copy + Neg<Output=T>, U> TypedPoint3D<T, U> {
+    /// Converts this point between a right-handed and a left-handed coordinate system
+    /// by negating its z coordinate.
+    ///
+    /// This matches `TypedTransform3D::convert_handedness`.
+    #[inline]
+    pub fn flip_handedness(&self) -> Self {
+        point3(self.x, self.y, -self.z)
+    }
+}
+
 impl<T: Copy + Add<T, Output=T>, U> AddAssign<TypedVector3D<T, U>> for TypedPoint3D<T, U> {
     #[inline]
     fn add_assign(&mut self, other: TypedVector3D<T, U>) {
@@ -557,15 +679,67 @@ impl<T: Copy + Div<T, Output=T>, U> Div<T> for TypedPoint3D<T, U> {
     }
 }
 
-impl<T: Float, U> TypedPoint3D<T, U> {
+impl<T: Copy + PartialOrd, U> TypedPoint3D<T, U> {
+    /// Returns the component-wise minimum of this point and another one.
+    ///
+    /// Like `Float::min`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn min(self, other: Self) -> Self {
-         point3(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+        point3(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+            partial_min(self.z, other.z),
+        )
     }
 
+    /// Returns the component-wise maximum of this point and another one.
+    ///
+    /// Like `Float::max`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn max(self, other: Self) -> Self {
-        point3(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+        point3(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+            partial_max(self.z, other.z),
+        )
+    }
+}
+
+impl<T: Float, U> TypedPoint3D<T, U> {
+    /// Returns the same point with negative zero components replaced by positive zero,
+    /// so that values can be compared or hashed bitwise.
+    #[inline]
+    pub fn normalize_zero(&self) -> Self {
+        // Adding positive zero maps -0.0 to +0.0 and leaves other values unchanged.
+        let zero = T::zero();
+        point3(self.x + zero, self.y + zero, self.z + zero)
+    }
+
+    /// Returns true if this point and `other` are equal up to a tolerance relative to
+    /// their magnitude. See `TypedPoint2D::approx_eq_relative`.
+    #[inline]
+    pub fn approx_eq_relative(&self, other: &Self, rel_eps: T) -> bool {
+        let scale = self.x.abs().max(self.y.abs()).max(self.z.abs())
+            .max(other.x.abs()).max(other.y.abs()).max(other.z.abs());
+        let eps = rel_eps * scale;
+        (self.x - other.x).abs() <= eps &&
+            (self.y - other.y).abs() <= eps &&
+            (self.z - other.z).abs() <= eps
+    }
+
+    /// Returns true if all of the components of this point are finite, that is
+    /// neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns true if all of the components of this point are within the
+    /// `ApproxEq` epsilon of zero.
+    #[inline]
+    pub fn approx_zero(&self) -> bool where T: ApproxEq<T> {
+        let zero = T::zero();
+        self.x.approx_eq(&zero) && self.y.approx_eq(&zero) && self.z.approx_eq(&zero)
     }
 }
 
@@ -696,19 +870,204 @@ impl<T: Copy, U> From<[T; 3]> for TypedPoint3D<T, U> {
     }
 }
 
+impl_byte_conversions!(TypedPoint3D, 3, {x, y, z});
+impl_component_indexing!(TypedPoint3D, 3, {0 => x, 1 => y, 2 => z});
 
+/// Shorthand for `TypedPoint2D::new(x, y)`.
+///
+/// The unit is inferred from the context, for example `UnknownUnit` when the result
+/// is used as a `Point2D`.
+#[inline]
 pub fn point2<T: Copy, U>(x: T, y: T) -> TypedPoint2D<T, U> {
     TypedPoint2D::new(x, y)
 }
 
+/// Shorthand for `TypedPoint3D::new(x, y, z)`.
+#[inline]
 pub fn point3<T: Copy, U>(x: T, y: T, z: T) -> TypedPoint3D<T, U> {
     TypedPoint3D::new(x, y, z)
 }
 
+/// Weights of the four control points of a cubic bezier curve at `t`.
+fn cubic_bezier_weights<T: Float>(t: T) -> [T; 4] {
+    let one = T::one();
+    let three = one + one + one;
+    let one_t = one - t;
+    [
+        one_t * one_t * one_t,
+        three * t * one_t * one_t,
+        three * t * t * one_t,
+        t * t * t,
+    ]
+}
+
+/// Weights of the four control points of a uniform Catmull-Rom spline at `t`.
+fn catmull_rom_weights<T: Float>(t: T) -> [T; 4] {
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+    let four = two + two;
+    let five = four + one;
+    let half = one / two;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        half * (two * t2 - t - t3),
+        half * (two - five * t2 + three * t3),
+        half * (t + four * t2 - three * t3),
+        half * (t3 - t2),
+    ]
+}
+
+/// Evaluates the cubic bezier curve defined by four control points at `t`.
+///
+/// `t` is expected to be between zero and one, the curve starting at `p0` and
+/// ending at `p3`.
+pub fn cubic_bezier<T: Float, U>(
+    p0: TypedPoint2D<T, U>,
+    p1: TypedPoint2D<T, U>,
+    p2: TypedPoint2D<T, U>,
+    p3: TypedPoint2D<T, U>,
+    t: T,
+) -> TypedPoint2D<T, U> {
+    let w = cubic_bezier_weights(t);
+    point2(
+        w[0] * p0.x + w[1] * p1.x + w[2] * p2.x + w[3] * p3.x,
+        w[0] * p0.y + w[1] * p1.y + w[2] * p2.y + w[3] * p3.y,
+    )
+}
+
+/// Evaluates the uniform Catmull-Rom spline segment between `p1` and `p2` at `t`,
+/// using `p0` and `p3` as the neighbouring points.
+///
+/// `t` is expected to be between zero and one, the curve starting at `p1` and
+/// ending at `p2`.
+pub fn catmull_rom<T: Float, U>(
+    p0: TypedPoint2D<T, U>,
+    p1: TypedPoint2D<T, U>,
+    p2: TypedPoint2D<T, U>,
+    p3: TypedPoint2D<T, U>,
+    t: T,
+) -> TypedPoint2D<T, U> {
+    let w = catmull_rom_weights(t);
+    point2(
+        w[0] * p0.x + w[1] * p1.x + w[2] * p2.x + w[3] * p3.x,
+        w[0] * p0.y + w[1] * p1.y + w[2] * p2.y + w[3] * p3.y,
+    )
+}
+
+/// Evaluates the 3d cubic bezier curve defined by four control points at `t`.
+///
+/// See `cubic_bezier`.
+pub fn cubic_bezier_3d<T: Float, U>(
+    p0: TypedPoint3D<T, U>,
+    p1: TypedPoint3D<T, U>,
+    p2: TypedPoint3D<T, U>,
+    p3: TypedPoint3D<T, U>,
+    t: T,
+) -> TypedPoint3D<T, U> {
+    let w = cubic_bezier_weights(t);
+    point3(
+        w[0] * p0.x + w[1] * p1.x + w[2] * p2.x + w[3] * p3.x,
+        w[0] * p0.y + w[1] * p1.y + w[2] * p2.y + w[3] * p3.y,
+        w[0] * p0.z + w[1] * p1.z + w[2] * p2.z + w[3] * p3.z,
+    )
+}
+
+/// Evaluates the 3d uniform Catmull-Rom spline segment between `p1` and `p2` at `t`.
+///
+/// See `catmull_rom`.
+pub fn catmull_rom_3d<T: Float, U>(
+    p0: TypedPoint3D<T, U>,
+    p1: TypedPoint3D<T, U>,
+    p2: TypedPoint3D<T, U>,
+    p3: TypedPoint3D<T, U>,
+    t: T,
+) -> TypedPoint3D<T, U> {
+    let w = catmull_rom_weights(t);
+    point3(
+        w[0] * p0.x + w[1] * p1.x + w[2] * p2.x + w[3] * p3.x,
+        w[0] * p0.y + w[1] * p1.y + w[2] * p2.y + w[3] * p3.y,
+        w[0] * p0.z + w[1] * p1.z + w[2] * p2.z + w[3] * p3.z,
+    )
+}
+
+/// Accumulates the mean of a stream of points without storing them.
+///
+/// The mean is updated incrementally with each new point, Welford-style, rather than
+/// divided out of a running sum, so it stays accurate and never overflows over
+/// millions of points.
+pub struct MeanPoint2D<T, U> {
+    mean: TypedPoint2D<T, U>,
+    count: usize,
+}
+
+impl<T: Float, U> MeanPoint2D<T, U> {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        MeanPoint2D {
+            mean: TypedPoint2D::origin(),
+            count: 0,
+        }
+    }
+
+    /// Adds a point to the accumulated mean.
+    pub fn push(&mut self, point: TypedPoint2D<T, U>) {
+        self.count += 1;
+        let n: T = NumCast::from(self.count).unwrap();
+        self.mean = self.mean + (point - self.mean) / n;
+    }
+
+    /// Returns the number of points pushed so far.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the points pushed so far, or `None` if no point was pushed.
+    #[inline]
+    pub fn mean(&self) -> Option<TypedPoint2D<T, U>> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.mean)
+    }
+}
+
+impl<T: Float, U> Default for MeanPoint2D<T, U> {
+    fn default() -> Self {
+        MeanPoint2D::new()
+    }
+}
+
+impl<T: Clone, U> Clone for MeanPoint2D<T, U> {
+    fn clone(&self) -> Self {
+        MeanPoint2D {
+            mean: self.mean.clone(),
+            count: self.count,
+        }
+    }
+}
+
+impl<T: Copy, U> Copy for MeanPoint2D<T, U> {}
+
+impl<T: fmt::Debug, U> fmt::Debug for MeanPoint2D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MeanPoint2D({:?}, {})", self.mean, self.count)
+    }
+}
+
 #[cfg(test)]
 mod point2d {
     use super::Point2D;
 
+    #[test]
+    pub fn test_normalize_zero() {
+        let p = Point2D::new(-0.0f32, -0.0).normalize_zero();
+        assert!(p.x.is_sign_positive());
+        assert!(p.y.is_sign_positive());
+    }
+
     #[test]
     pub fn test_scalar_mul() {
         let p1: Point2D<f32> = Point2D::new(3.0, 5.0);
@@ -737,6 +1096,131 @@ mod point2d {
 
         assert_eq!(result, Point2D::new(2.0, 3.0));
     }
+
+    #[test]
+    pub fn test_min_max() {
+        let points = [
+            Point2D::new(1, 3),
+            Point2D::new(-2, 5),
+            Point2D::new(4, -1),
+        ];
+
+        let result = Point2D::min_max(&points);
+
+        assert_eq!(result, Some((Point2D::new(-2, -1), Point2D::new(4, 5))));
+        assert_eq!(Point2D::<f32>::min_max(&[]), None);
+    }
+
+    #[test]
+    pub fn test_min_max_nan() {
+        let nan = f32::NAN;
+        let p1 = Point2D::new(nan, 1.0);
+        let p2 = Point2D::new(2.0, nan);
+
+        assert_eq!(p1.min(p2), Point2D::new(2.0, 1.0));
+        assert_eq!(p2.min(p1), Point2D::new(2.0, 1.0));
+        assert_eq!(p1.max(p2), Point2D::new(2.0, 1.0));
+        assert_eq!(p2.max(p1), Point2D::new(2.0, 1.0));
+
+        let points = [p1, Point2D::new(1.0, 3.0), p2];
+        let result = Point2D::min_max(&points);
+        assert_eq!(result, Some((Point2D::new(1.0, 1.0), Point2D::new(2.0, 3.0))));
+    }
+
+    #[test]
+    pub fn test_vector_conversion() {
+        use vector::vec2;
+
+        let p = Point2D::new(1.0, -2.0);
+        assert_eq!(p.to_vector(), vec2(1.0, -2.0));
+        assert_eq!(Point2D::from_vector(p.to_vector()), p);
+        assert_eq!(Point2D::from_vector(vec2(3.0, 4.0)), Point2D::origin() + vec2(3.0, 4.0));
+    }
+
+    #[test]
+    pub fn test_approx_eq_relative() {
+        use approxeq::ApproxEq;
+
+        let p1 = Point2D::new(1.0e6f32, 1.0e6);
+        let p2 = Point2D::new(1.0e6 + 0.5, 1.0e6 - 0.5);
+        assert!(p1.approx_eq_relative(&p2, 1.0e-6));
+        assert!(!p1.approx_eq_relative(&p2, 1.0e-7));
+        assert!(!p1.approx_eq(&p2));
+
+        assert!(Point2D::new(0.0f32, 0.0).approx_eq_relative(&Point2D::new(0.0, 0.0), 1.0e-6));
+        assert!(!Point2D::new(0.0f32, 0.0).approx_eq_relative(&Point2D::new(0.0, 1.0e-9), 1.0e-6));
+    }
+
+    #[test]
+    pub fn test_rotate_by_sin_cos() {
+        use {Angle, TypedRotation2D};
+        use approxeq::ApproxEq;
+
+        let angle = Angle::radians(0.7f32);
+        let rotation: TypedRotation2D<f32, _, _> = TypedRotation2D::new(angle);
+        let (sin, cos) = angle.sin_cos();
+        let mut points = [Point2D::new(1.0, 2.0), Point2D::new(-3.0, 0.5), Point2D::new(0.0, 0.0)];
+        let expected: Vec<Point2D<f32>> = points.iter().map(|p| rotation.transform_point(p)).collect();
+
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert!(p.rotate_by_sin_cos(sin, cos).approx_eq(e));
+            assert!(p.to_vector().rotate_by_sin_cos(sin, cos).approx_eq(&e.to_vector()));
+        }
+
+        Point2D::rotate_slice(&mut points, angle);
+        for (p, e) in points.iter().zip(expected.iter()) {
+            assert!(p.approx_eq(e));
+        }
+    }
+
+    #[test]
+    pub fn test_is_finite_and_approx_zero() {
+        assert!(Point2D::new(1.0, -2.0).is_finite());
+        assert!(!Point2D::new(f32::NAN, 0.0).is_finite());
+        assert!(!Point2D::new(0.0, f32::INFINITY).is_finite());
+
+        assert!(Point2D::new(0.0, 1.0e-8).approx_zero());
+        assert!(!Point2D::new(0.1, 0.0).approx_zero());
+    }
+
+    #[test]
+    pub fn test_mean_point() {
+        use super::MeanPoint2D;
+
+        let mut acc = MeanPoint2D::new();
+        assert_eq!(acc.mean(), None);
+
+        acc.push(Point2D::new(0.0, 0.0));
+        acc.push(Point2D::new(2.0, 0.0));
+        acc.push(Point2D::new(0.0, 2.0));
+        assert_eq!(acc.count(), 3);
+        let mean = acc.mean().unwrap();
+        assert!((mean.x - 2.0 / 3.0f64).abs() < 1.0e-12);
+        assert!((mean.y - 2.0 / 3.0f64).abs() < 1.0e-12);
+
+        // No overflow of a running sum in f32.
+        let mut acc = MeanPoint2D::new();
+        for _ in 0..1000 {
+            acc.push(Point2D::new(3.0e38f32, -3.0e38));
+        }
+        assert_eq!(acc.mean(), Some(Point2D::new(3.0e38, -3.0e38)));
+    }
+
+    #[test]
+    pub fn test_index() {
+        let mut p = Point2D::new(1, 2);
+        assert_eq!((p[0], p[1]), (1, 2));
+        p[1] = 5;
+        assert_eq!(p, Point2D::new(1, 5));
+        assert_eq!(p.iter().sum::<i32>(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_index_out_of_range() {
+        let mut p = Point2D::new(1, 2);
+        p[2] = 3;
+    }
 }
 
 #[cfg(test)]
@@ -799,6 +1283,53 @@ mod typedpoint2d {
     }
 }
 
+#[cfg(test)]
+mod curves {
+    use super::{Point2D, Point3D, point2, point3};
+    use super::{cubic_bezier, catmull_rom, cubic_bezier_3d, catmull_rom_3d};
+    use approxeq::ApproxEq;
+
+    #[test]
+    pub fn test_cubic_bezier() {
+        let p0: Point2D<f32> = point2(0.0, 0.0);
+        let p1 = point2(1.0, 2.0);
+        let p2 = point2(3.0, -1.0);
+        let p3 = point2(4.0, 4.0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(cubic_bezier(p0, p1, p2, p3, 1.0), p3);
+
+        // A straight control polygon evaluates to points on the line.
+        let mid = cubic_bezier(p0, point2(1.0, 1.0), point2(2.0, 2.0), point2(3.0, 3.0), 0.5);
+        assert!(mid.approx_eq(&point2(1.5, 1.5)));
+
+        let p: Point3D<f32> = cubic_bezier_3d(
+            point3(0.0, 0.0, 0.0), point3(1.0, 1.0, 2.0), point3(2.0, 2.0, 4.0), point3(3.0, 3.0, 6.0),
+            0.5,
+        );
+        assert!(p.approx_eq(&point3(1.5, 1.5, 3.0)));
+    }
+
+    #[test]
+    pub fn test_catmull_rom() {
+        let p0: Point2D<f32> = point2(-1.0, 3.0);
+        let p1 = point2(0.0, 0.0);
+        let p2 = point2(2.0, 1.0);
+        let p3 = point2(5.0, -2.0);
+        assert!(catmull_rom(p0, p1, p2, p3, 0.0).approx_eq(&p1));
+        assert!(catmull_rom(p0, p1, p2, p3, 1.0).approx_eq(&p2));
+
+        // Evenly spaced collinear points are interpolated linearly.
+        let p: Point2D<f32> = catmull_rom(point2(0.0, 0.0), point2(1.0, 2.0), point2(2.0, 4.0), point2(3.0, 6.0), 0.25);
+        assert!(p.approx_eq(&point2(1.25, 2.5)));
+
+        let p: Point3D<f32> = catmull_rom_3d(
+            point3(0.0, 0.0, 0.0), point3(1.0, 1.0, 1.0), point3(2.0, 2.0, 2.0), point3(3.0, 3.0, 3.0),
+            0.5,
+        );
+        assert!(p.approx_eq(&point3(1.5, 1.5, 1.5)));
+    }
+}
+
 #[cfg(test)]
 mod point3d {
     use super::{Point3D, point2, point3};
@@ -843,4 +1374,41 @@ mod point3d {
         assert_eq!(p.xz(), point2(1, 3));
         assert_eq!(p.yz(), point2(2, 3));
     }
+
+    #[test]
+    pub fn test_flip_handedness() {
+        let p: Point3D<f32> = point3(1.0, -2.0, 3.0);
+        assert_eq!(p.flip_handedness(), point3(1.0, -2.0, -3.0));
+        assert_eq!(p.flip_handedness().flip_handedness(), p);
+    }
+
+    #[test]
+    pub fn test_vector_conversion() {
+        use vector::vec3;
+
+        let p: Point3D<f32> = point3(1.0, -2.0, 3.0);
+        assert_eq!(p.to_vector(), vec3(1.0, -2.0, 3.0));
+        assert_eq!(Point3D::from_vector(p.to_vector()), p);
+    }
+
+    #[test]
+    pub fn test_approx_eq_relative() {
+        // The tolerance scales with the z component too.
+        let p1: Point3D<f64> = point3(0.0, 0.0, 1.0e6);
+        let p2: Point3D<f64> = point3(0.5, 0.0, 1.0e6);
+        assert!(p1.approx_eq_relative(&p2, 1.0e-6));
+
+        let p3: Point3D<f64> = point3(0.0, 0.0, 1.0e3);
+        let p4: Point3D<f64> = point3(0.5, 0.0, 1.0e3);
+        assert!(!p3.approx_eq_relative(&p4, 1.0e-6));
+    }
+
+    #[test]
+    pub fn test_is_finite_and_approx_zero() {
+        assert!(Point3D::new(1.0, -2.0, 3.0).is_finite());
+        assert!(!Point3D::new(0.0, f32::NAN, 0.0).is_finite());
+
+        assert!(Point3D::<f32>::origin().approx_zero());
+        assert!(!Point3D::new(0.0, 0.0, -0.1).approx_zero());
+    }
 }
@@ -0,0 +1,138 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Geometric predicates and intersection tests between 2d primitives.
+//!
+//! ```rust
+//! use euclid::{point2, Point2D};
+//! use euclid::query::{point_in_triangle, segment_intersection};
+//!
+//! let (a, b, c): (Point2D<f32>, _, _) = (point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 4.0));
+//! assert!(point_in_triangle(point2(1.0, 1.0), a, b, c));
+//!
+//! let crossing = segment_intersection(a, point2(4.0, 4.0), b, c);
+//! assert_eq!(crossing, Some(point2(2.0, 2.0)));
+//! ```
+
+use num::Zero;
+use num_traits::Float;
+use point::TypedPoint2D;
+use std::ops::{Add, Mul, Sub};
+
+/// Returns true if `p` is inside the triangle `abc`, or on one of its edges.
+///
+/// This checks that `p` is on the same side of the three edges using the sign of the
+/// 2d cross product, so the triangle can wind in either direction. Degenerate
+/// triangles, whose points are collinear, contain no point.
+pub fn point_in_triangle<T, U>(
+    p: TypedPoint2D<T, U>,
+    a: TypedPoint2D<T, U>,
+    b: TypedPoint2D<T, U>,
+    c: TypedPoint2D<T, U>,
+) -> bool
+where T: Copy + Zero + PartialOrd + Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T> {
+    let zero = T::zero();
+    if (b - a).cross(c - a) == zero {
+        return false;
+    }
+
+    let d1 = (b - a).cross(p - a);
+    let d2 = (c - b).cross(p - b);
+    let d3 = (a - c).cross(p - c);
+    let has_negative = d1 < zero || d2 < zero || d3 < zero;
+    let has_positive = d1 > zero || d2 > zero || d3 > zero;
+    !(has_negative && has_positive)
+}
+
+/// Returns the point where the segment from `p1` to `p2` crosses the segment from
+/// `p3` to `p4`, or `None` if they don't intersect.
+///
+/// Segments that touch at an end point intersect at that point. Parallel segments
+/// return `None`, including collinear segments that overlap or share an end point,
+/// since they have no single crossing point.
+pub fn segment_intersection<T: Float, U>(
+    p1: TypedPoint2D<T, U>,
+    p2: TypedPoint2D<T, U>,
+    p3: TypedPoint2D<T, U>,
+    p4: TypedPoint2D<T, U>,
+) -> Option<TypedPoint2D<T, U>> {
+    let r = p2 - p1;
+    let s = p4 - p3;
+    let denom = r.cross(s);
+    if denom == T::zero() {
+        return None;
+    }
+
+    // p1 + r * t == p3 + s * u
+    let v = p3 - p1;
+    let t = v.cross(s) / denom;
+    let u = v.cross(r) / denom;
+    let (zero, one) = (T::zero(), T::one());
+    if t < zero || t > one || u < zero || u > one {
+        return None;
+    }
+
+    Some(p1 + r * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use point::{Point2D, point2};
+
+    #[test]
+    fn test_point_in_triangle() {
+        let (a, b, c): (Point2D<f32>, _, _) = (point2(0.0, 0.0), point2(4.0, 0.0), point2(0.0, 4.0));
+        assert!(point_in_triangle(point2(1.0, 1.0), a, b, c));
+        assert!(point_in_triangle(point2(1.0, 1.0), a, c, b));
+        assert!(point_in_triangle(point2(2.0, 0.0), a, b, c));
+        assert!(point_in_triangle(a, a, b, c));
+        assert!(!point_in_triangle(point2(3.0, 3.0), a, b, c));
+        assert!(!point_in_triangle(point2(-1.0, 1.0), a, b, c));
+
+        let (a, b, c): (Point2D<i32>, _, _) = (point2(0, 0), point2(10, 0), point2(5, 10));
+        assert!(point_in_triangle(point2(5, 5), a, b, c));
+        assert!(!point_in_triangle(point2(0, 5), a, b, c));
+
+        // Degenerate triangle.
+        let (a, b, c): (Point2D<f32>, _, _) = (point2(0.0, 0.0), point2(1.0, 1.0), point2(2.0, 2.0));
+        assert!(!point_in_triangle(point2(1.0, 1.0), a, b, c));
+    }
+
+    #[test]
+    fn test_segment_intersection() {
+        let p: Point2D<f32> = point2(0.0, 0.0);
+        assert_eq!(
+            segment_intersection(p, point2(4.0, 4.0), point2(0.0, 4.0), point2(4.0, 0.0)),
+            Some(point2(2.0, 2.0))
+        );
+        // Shared end point.
+        assert_eq!(
+            segment_intersection(p, point2(4.0, 0.0), point2(4.0, 0.0), point2(4.0, 4.0)),
+            Some(point2(4.0, 0.0))
+        );
+        // The lines cross outside of the segments.
+        assert_eq!(segment_intersection(p, point2(1.0, 1.0), point2(0.0, 4.0), point2(4.0, 0.0)), None);
+        // Parallel and collinear segments.
+        assert_eq!(segment_intersection(p, point2(4.0, 0.0), point2(0.0, 1.0), point2(4.0, 1.0)), None);
+        assert_eq!(segment_intersection(p, point2(4.0, 0.0), point2(2.0, 0.0), point2(6.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_segment_intersection_near_parallel() {
+        let p1: Point2D<f64> = point2(0.0, 0.0);
+        let p2 = point2(10.0, 0.0);
+
+        let crossing = segment_intersection(p1, p2, point2(0.0, 1.0e-6), point2(10.0, -1.0e-6)).unwrap();
+        assert!((crossing.x - 5.0).abs() < 1.0e-9);
+        assert!(crossing.y.abs() < 1.0e-9);
+
+        assert_eq!(segment_intersection(p1, p2, point2(0.0, 1.0e-6), point2(10.0, 2.0e-6)), None);
+    }
+}
@@ -13,9 +13,10 @@ use scale::TypedScale;
 use num::*;
 use point::TypedPoint2D;
 use vector::TypedVector2D;
+use side_offsets::TypedSideOffsets2D;
 use size::TypedSize2D;
 
-use num_traits::NumCast;
+use num_traits::{Float, NumCast};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::cmp::PartialOrd;
 use std::fmt;
@@ -32,6 +33,40 @@ pub struct TypedRect<T, U = UnknownUnit> {
 /// The default rectangle type with no unit.
 pub type Rect<T> = TypedRect<T, UnknownUnit>;
 
+/// Horizontal alignment of a rectangle within another, see `TypedRect::align_within`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HorizontalAlign {
+    /// Align the left edges.
+    Start,
+    /// Align the horizontal centers.
+    Center,
+    /// Align the right edges.
+    End,
+}
+
+/// Vertical alignment of a rectangle within another, see `TypedRect::align_within`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerticalAlign {
+    /// Align the top edges.
+    Start,
+    /// Align the vertical centers.
+    Center,
+    /// Align the bottom edges.
+    End,
+}
+
+/// Which edges of a rectangle count as inside, see `TypedRect::contains_with`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EdgeInclusion {
+    /// The left and top edges are inside, the right and bottom edges are outside.
+    /// This is what `TypedRect::contains` uses.
+    HalfOpen,
+    /// All edges are inside.
+    Closed,
+    /// All edges are outside.
+    Open,
+}
+
 impl<'de, T: Copy + Deserialize<'de>, U> Deserialize<'de> for TypedRect<T, U> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
@@ -85,7 +120,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedRect<T, U> {
 
 impl<T, U> TypedRect<T, U> {
     /// Constructor.
-    pub fn new(origin: TypedPoint2D<T, U>, size: TypedSize2D<T, U>) -> Self {
+    pub const fn new(origin: TypedPoint2D<T, U>, size: TypedSize2D<T, U>) -> Self {
         TypedRect {
             origin: origin,
             size: size,
@@ -174,6 +209,23 @@ where T: Copy + Clone + Zero + PartialOrd + PartialEq + Add<T, Output=T> + Sub<T
         self.origin.y <= other.y && other.y < self.origin.y + self.size.height
     }
 
+    /// Returns true if this rectangle contains the point, with `edges` selecting
+    /// whether points on the edges are considered inside the rectangle.
+    #[inline]
+    pub fn contains_with(&self, p: TypedPoint2D<T, U>, edges: EdgeInclusion) -> bool {
+        let (min_x, max_x) = (self.origin.x, self.max_x());
+        let (min_y, max_y) = (self.origin.y, self.max_y());
+        match edges {
+            EdgeInclusion::HalfOpen => self.contains(&p),
+            EdgeInclusion::Closed => {
+                min_x <= p.x && p.x <= max_x && min_y <= p.y && p.y <= max_y
+            }
+            EdgeInclusion::Open => {
+                min_x < p.x && p.x < max_x && min_y < p.y && p.y < max_y
+            }
+        }
+    }
+
     /// Returns true if this rectangle contains the interior of rect. Always
     /// returns true if rect is empty, and always returns false if rect is
     /// nonempty but this rectangle is empty.
@@ -184,6 +236,35 @@ where T: Copy + Clone + Zero + PartialOrd + PartialEq + Add<T, Output=T> + Sub<T
              self.min_y() <= rect.min_y() && rect.max_y() <= self.max_y())
     }
 
+    /// Splits the space of this rectangle that is left free after placing `placed` in
+    /// its top-left corner, as done by guillotine rectangle packers.
+    ///
+    /// The cut is made vertically along the right edge of `placed`, then horizontally
+    /// along its bottom edge. This returns the right remainder, which spans the whole
+    /// height of this rectangle, and the bottom remainder, which is as wide as `placed`.
+    /// For example placing a 40x30 rectangle in a 100x100 one gives a 60x100 right
+    /// remainder and a 40x70 bottom remainder.
+    ///
+    /// Only the size of `placed` is used, as it is assumed to be anchored at this
+    /// rectangle's origin. Remainders are empty when `placed` takes the whole width or
+    /// height; if it is larger than this rectangle, it is treated as if it had been
+    /// clamped to this rectangle's size.
+    pub fn guillotine_split(&self, placed: &Self) -> (Self, Self) {
+        // Clamping to this rectangle's size keeps the remainders from being negative.
+        let width = if placed.size.width < self.size.width { placed.size.width } else { self.size.width };
+        let height = if placed.size.height < self.size.height { placed.size.height } else { self.size.height };
+
+        let right = TypedRect::new(
+            TypedPoint2D::new(self.origin.x + width, self.origin.y),
+            TypedSize2D::new(self.size.width - width, self.size.height),
+        );
+        let bottom = TypedRect::new(
+            TypedPoint2D::new(self.origin.x, self.origin.y + height),
+            TypedSize2D::new(width, self.size.height - height),
+        );
+        (right, bottom)
+    }
+
     #[inline]
     #[cfg_attr(feature = "unstable", must_use)]
     pub fn inflate(&self, width: T, height: T) -> Self {
@@ -214,6 +295,39 @@ where T: Copy + Clone + Zero + PartialOrd + PartialEq + Add<T, Output=T> + Sub<T
         TypedPoint2D::new(self.max_x(), self.max_y())
     }
 
+    /// Returns the four edges of this rectangle as pairs of points, in the
+    /// following order: top, right, bottom, left.
+    ///
+    /// Each edge starts where the previous one ends, so that the edges go
+    /// clockwise around the rectangle when the y axis points down (starting
+    /// from the origin).
+    #[inline]
+    pub fn edges(&self) -> [(TypedPoint2D<T, U>, TypedPoint2D<T, U>); 4] {
+        let top_left = self.origin;
+        let top_right = self.top_right();
+        let bottom_right = self.bottom_right();
+        let bottom_left = self.bottom_left();
+        [
+            (top_left, top_right),
+            (top_right, bottom_right),
+            (bottom_right, bottom_left),
+            (bottom_left, top_left),
+        ]
+    }
+
+    /// Returns the same rectangle, with its vertical position flipped inside of a
+    /// container of the given height whose top is at zero. The size is preserved.
+    ///
+    /// This is useful to convert between y-down and y-up coordinate systems.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn flip_y_within(&self, container_height: T) -> Self {
+        TypedRect::new(
+            TypedPoint2D::new(self.origin.x, container_height - self.max_y()),
+            self.size,
+        )
+    }
+
     #[inline]
     #[cfg_attr(feature = "unstable", must_use)]
     pub fn translate_by_size(&self, size: &TypedSize2D<T, U>) -> Self {
@@ -264,11 +378,44 @@ where T: Copy + Clone + Zero + PartialOrd + PartialEq + Add<T, Output=T> + Sub<T
     }
 }
 
+impl<T, U> TypedRect<T, U>
+where T: Copy + Add<T, Output=T> + Sub<T, Output=T> {
+    /// Returns this rectangle grown by the given offsets on each side, for example
+    /// to compute a border box from a padding box.
+    #[inline]
+    pub fn outer_rect(&self, offsets: TypedSideOffsets2D<T, U>) -> Self {
+        TypedRect::new(
+            TypedPoint2D::new(self.origin.x - offsets.left, self.origin.y - offsets.top),
+            TypedSize2D::new(
+                self.size.width + offsets.horizontal(),
+                self.size.height + offsets.vertical(),
+            ),
+        )
+    }
+
+    /// Returns this rectangle shrunk by the given offsets on each side, for example
+    /// to compute a padding box from a border box.
+    #[inline]
+    pub fn inner_rect(&self, offsets: TypedSideOffsets2D<T, U>) -> Self {
+        TypedRect::new(
+            TypedPoint2D::new(self.origin.x + offsets.left, self.origin.y + offsets.top),
+            TypedSize2D::new(
+                self.size.width - offsets.horizontal(),
+                self.size.height - offsets.vertical(),
+            ),
+        )
+    }
+}
+
 impl<T, U> TypedRect<T, U>
 where T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
     /// Linearly interpolate between this rectangle and another rectangle.
     ///
-    /// `t` is expected to be between zero and one.
+    /// `t` is expected to be between zero and one, values outside of this range
+    /// extrapolate like the other `lerp` methods.
+    ///
+    /// The origin and the size are interpolated independently. Since the interpolation
+    /// is linear, this gives the same result as interpolating the min and max corners.
     #[inline]
     pub fn lerp(&self, other: Self, t: T) -> Self {
         Self::new(
@@ -278,6 +425,49 @@ where T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
     }
 }
 
+impl<T, U> TypedRect<T, U>
+where T: Copy + One + Add<Output=T> + Sub<Output=T> + Div<Output=T> {
+    /// Constructs a rectangle of the given size centered on a point.
+    ///
+    /// The origin is computed as `center - size / 2`. For integer types the
+    /// halved size is truncated, so a rectangle with an odd size gets the
+    /// extra unit on its right and bottom side. The result always round-trips
+    /// through `center()`.
+    #[inline]
+    pub fn from_center_and_size(center: TypedPoint2D<T, U>, size: TypedSize2D<T, U>) -> Self {
+        let two = T::one() + T::one();
+        let half = TypedVector2D::new(size.width / two, size.height / two);
+        TypedRect::new(center - half, size)
+    }
+
+    /// Returns the center of the rectangle, computed as `origin + size / 2`.
+    ///
+    /// For integer types the halved size is truncated.
+    #[inline]
+    pub fn center(&self) -> TypedPoint2D<T, U> {
+        let two = T::one() + T::one();
+        self.origin + TypedVector2D::new(self.size.width / two, self.size.height / two)
+    }
+
+    /// Returns half of the size of the rectangle, that is the extent from its center
+    /// to its edges along each axis.
+    ///
+    /// For integer types the halved size is truncated like in `center()`, which
+    /// rounds down for non-negative sizes.
+    #[inline]
+    pub fn half_size(&self) -> TypedSize2D<T, U> {
+        let two = T::one() + T::one();
+        TypedSize2D::new(self.size.width / two, self.size.height / two)
+    }
+
+    /// Returns the vector from the top-left corner to the bottom-right corner of
+    /// the rectangle.
+    #[inline]
+    pub fn diagonal(&self) -> TypedVector2D<T, U> {
+        self.size.to_vector()
+    }
+}
+
 impl<T, U> TypedRect<T, U>
 where T: Copy + Clone + PartialOrd + Add<T, Output=T> + Sub<T, Output=T> + Zero {
     #[inline]
@@ -300,6 +490,270 @@ where T: Copy + Clone + PartialOrd + Add<T, Output=T> + Sub<T, Output=T> + Zero
             TypedSize2D::new(lower_right_x - upper_left.x, lower_right_y - upper_left.y)
         )
     }
+
+    /// Returns up to four non-overlapping rectangles covering the part of this
+    /// rectangle that is not covered by `other`.
+    ///
+    /// Returns this rectangle unchanged if the two rectangles don't intersect, and
+    /// an empty vector if `other` covers this rectangle entirely. The pieces are
+    /// ordered top, bottom, left, right: the top and bottom pieces span the whole
+    /// width of this rectangle, while the left and right ones only span the height
+    /// of the intersection.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let intersection = match self.intersection(other) {
+            Some(intersection) => intersection,
+            None => return vec![*self],
+        };
+
+        let mut result = Vec::with_capacity(4);
+        let mut push = |x0: T, y0: T, x1: T, y1: T| {
+            if x0 < x1 && y0 < y1 {
+                result.push(TypedRect::new(
+                    TypedPoint2D::new(x0, y0),
+                    TypedSize2D::new(x1 - x0, y1 - y0),
+                ));
+            }
+        };
+
+        push(self.min_x(), self.min_y(), self.max_x(), intersection.min_y());
+        push(self.min_x(), intersection.max_y(), self.max_x(), self.max_y());
+        push(self.min_x(), intersection.min_y(), intersection.min_x(), intersection.max_y());
+        push(intersection.max_x(), intersection.min_y(), self.max_x(), intersection.max_y());
+
+        result
+    }
+}
+
+impl<T: Float, U> TypedRect<T, U> {
+    /// Returns half of the length of the diagonal of the rectangle, which is the
+    /// radius of the smallest circle containing it, centered on `center()`.
+    #[inline]
+    pub fn radius(&self) -> T {
+        self.diagonal().length() / (T::one() + T::one())
+    }
+
+    /// Returns the point of this rectangle closest to `p`.
+    ///
+    /// Like `contains`, this treats the right and bottom edges as outside of the
+    /// rectangle: coordinates past them are clamped to the largest floating point
+    /// value below the edge, so that the result is always contained in non-empty
+    /// rectangles. Points that are already in the rectangle are returned unchanged.
+    #[inline]
+    pub fn clamp_point(&self, p: TypedPoint2D<T, U>) -> TypedPoint2D<T, U> {
+        TypedPoint2D::new(
+            p.x.max(self.min_x()).min(largest_below(self.max_x())),
+            p.y.max(self.min_y()).min(largest_below(self.max_y())),
+        )
+    }
+
+    /// Returns the smallest rectangle containing this one whose edges lie on the
+    /// lines of a grid starting at `origin` with cells of size `step`.
+    ///
+    /// Since the rounding is always outward and only depends on the grid, rectangles
+    /// that share an edge still touch after snapping: they share the edge if
+    /// it is on the grid, and overlap by at most one cell otherwise, but there is
+    /// never a gap between them.
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn snap_to_grid(&self, origin: TypedPoint2D<T, U>, step: TypedSize2D<T, U>) -> Self {
+        let min_x = ((self.min_x() - origin.x) / step.width).floor() * step.width + origin.x;
+        let min_y = ((self.min_y() - origin.y) / step.height).floor() * step.height + origin.y;
+        let max_x = ((self.max_x() - origin.x) / step.width).ceil() * step.width + origin.x;
+        let max_y = ((self.max_y() - origin.y) / step.height).ceil() * step.height + origin.y;
+        TypedRect::new(
+            TypedPoint2D::new(min_x, min_y),
+            TypedSize2D::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// Rounds this rectangle outward so that its edges are multiples of `tile`.
+    ///
+    /// This is `snap_to_grid` with square cells of size `tile` and a grid starting
+    /// at zero.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn round_out_to_multiple(&self, tile: T) -> Self {
+        self.snap_to_grid(TypedPoint2D::new(T::zero(), T::zero()), TypedSize2D::new(tile, tile))
+    }
+
+    /// Inflates this rectangle like `inflate` and clamps the result so that it stays
+    /// within `container`.
+    ///
+    /// The result is always contained in `container`, even if this rectangle is
+    /// not, in which case it may be empty.
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn inflate_clamped(&self, dx: T, dy: T, container: &Self) -> Self {
+        let clamp_x = |x: T| x.max(container.min_x()).min(container.max_x());
+        let clamp_y = |y: T| y.max(container.min_y()).min(container.max_y());
+        let min_x = clamp_x(self.min_x() - dx);
+        let min_y = clamp_y(self.min_y() - dy);
+        let max_x = clamp_x(self.max_x() + dx).max(min_x);
+        let max_y = clamp_y(self.max_y() + dy).max(min_y);
+        TypedRect::new(
+            TypedPoint2D::new(min_x, min_y),
+            TypedSize2D::new(max_x - min_x, max_y - min_y),
+        )
+    }
+
+    /// Deflates this rectangle by `dx` and `dy` on each side and clamps the result
+    /// so that it stays within `container`.
+    ///
+    /// This is equivalent to `inflate_clamped(-dx, -dy, container)`. The size of
+    /// the result does not go below zero.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn deflate_clamped(&self, dx: T, dy: T, container: &Self) -> Self {
+        self.inflate_clamped(-dx, -dy, container)
+    }
+
+    /// Returns the same rectangle, moved to the given alignment within `container`.
+    ///
+    /// The size is preserved, so the result may overflow `container` if this
+    /// rectangle is larger than it.
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn align_within(&self, container: &Self, h: HorizontalAlign, v: VerticalAlign) -> Self {
+        let two = T::one() + T::one();
+        let x = match h {
+            HorizontalAlign::Start => container.min_x(),
+            HorizontalAlign::Center => container.min_x() + (container.size.width - self.size.width) / two,
+            HorizontalAlign::End => container.max_x() - self.size.width,
+        };
+        let y = match v {
+            VerticalAlign::Start => container.min_y(),
+            VerticalAlign::Center => container.min_y() + (container.size.height - self.size.height) / two,
+            VerticalAlign::End => container.max_y() - self.size.height,
+        };
+        TypedRect::new(TypedPoint2D::new(x, y), self.size)
+    }
+}
+
+impl<T: Float, U> TypedRect<T, U> {
+    /// Returns true if the corners of this rectangle and `other` are equal up to a
+    /// tolerance relative to their magnitude, see `TypedPoint2D::approx_eq_relative`.
+    #[inline]
+    pub fn approx_eq_relative(&self, other: &Self, rel_eps: T) -> bool {
+        self.origin.approx_eq_relative(&other.origin, rel_eps) &&
+            self.bottom_right().approx_eq_relative(&other.bottom_right(), rel_eps)
+    }
+
+    /// Returns the smallest axis-aligned translation that moves this rectangle out of
+    /// `other`.
+    ///
+    /// The translation is along the axis with the smallest overlap, pointing away from
+    /// `other`. Returns a zero vector if the rectangles don't overlap or are just touching.
+    pub fn separation_vector(&self, other: &Self) -> TypedVector2D<T, U> {
+        let zero = T::zero();
+        let overlap_x = self.max_x().min(other.max_x()) - self.min_x().max(other.min_x());
+        let overlap_y = self.max_y().min(other.max_y()) - self.min_y().max(other.min_y());
+
+        if overlap_x <= zero || overlap_y <= zero {
+            return TypedVector2D::zero();
+        }
+
+        // Comparing the sums of the edges is equivalent to comparing the centers.
+        if overlap_x <= overlap_y {
+            let self_center = self.min_x() + self.max_x();
+            let other_center = other.min_x() + other.max_x();
+            if self_center < other_center {
+                TypedVector2D::new(-overlap_x, zero)
+            } else {
+                TypedVector2D::new(overlap_x, zero)
+            }
+        } else {
+            let self_center = self.min_y() + self.max_y();
+            let other_center = other.min_y() + other.max_y();
+            if self_center < other_center {
+                TypedVector2D::new(zero, -overlap_y)
+            } else {
+                TypedVector2D::new(zero, overlap_y)
+            }
+        }
+    }
+
+    /// Returns the smallest translation that brings this rectangle in contact with
+    /// `other`.
+    ///
+    /// Each component is zero if the rectangles already overlap on that axis.
+    pub fn gap_to(&self, other: &Self) -> TypedVector2D<T, U> {
+        let zero = T::zero();
+        let dx = if other.min_x() > self.max_x() {
+            other.min_x() - self.max_x()
+        } else if other.max_x() < self.min_x() {
+            other.max_x() - self.min_x()
+        } else {
+            zero
+        };
+        let dy = if other.min_y() > self.max_y() {
+            other.min_y() - self.max_y()
+        } else if other.max_y() < self.min_y() {
+            other.max_y() - self.min_y()
+        } else {
+            zero
+        };
+        TypedVector2D::new(dx, dy)
+    }
+
+    /// Intersects this rectangle with the ray starting at `origin` and going along `dir`,
+    /// using the slab method.
+    ///
+    /// Returns the parameters `t` of the points where the ray enters and exits the
+    /// rectangle, such that these points are `origin + dir * t`, or `None` if the ray
+    /// misses the rectangle. Only the part of the ray with `t >= 0` is considered, so
+    /// the entry parameter is zero if `origin` is inside the rectangle. Edges count as
+    /// inside, so a ray that grazes an edge or a corner returns a (possibly equal)
+    /// entry and exit.
+    ///
+    /// `dir` does not need to be normalized, and `t` is measured in multiples of its
+    /// length rather than in distance units. If one of its components is zero, the ray
+    /// is parallel to the corresponding pair of edges and always misses the
+    /// rectangle when it lies outside of them.
+    pub fn intersect_ray(&self, origin: TypedPoint2D<T, U>, dir: TypedVector2D<T, U>) -> Option<(T, T)> {
+        fn slab<T: Float>(start: T, dir: T, min: T, max: T, range: (T, T)) -> Option<(T, T)> {
+            if dir == T::zero() {
+                // Parallel to the slab, avoid dividing by zero.
+                return if start < min || start > max { None } else { Some(range) };
+            }
+            let t0 = (min - start) / dir;
+            let t1 = (max - start) / dir;
+            let (near, far) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+            let range = (range.0.max(near), range.1.min(far));
+            if range.0 > range.1 { None } else { Some(range) }
+        }
+
+        let range = (T::zero(), T::infinity());
+        let range = slab(origin.x, dir.x, self.min_x(), self.max_x(), range)?;
+        slab(origin.y, dir.y, self.min_y(), self.max_y(), range)
+    }
+
+    /// Returns the parameter `t` of the point where the ray starting at `origin` and
+    /// going along `dir` first hits this rectangle, such that this point is
+    /// `origin + dir * t`, or `None` if the ray misses the rectangle.
+    ///
+    /// As with `intersect_ray`, `t` is measured in multiples of the length of `dir`.
+    /// Returns zero if `origin` is inside the rectangle. See `intersect_ray`, which also
+    /// returns where the ray exits the rectangle.
+    ///
+    /// Unlike the other `intersects` methods this does not return a `bool`; use
+    /// `is_some()` on the result for a plain hit test.
+    #[inline]
+    pub fn intersects_ray(&self, origin: TypedPoint2D<T, U>, dir: TypedVector2D<T, U>) -> Option<T> {
+        self.intersect_ray(origin, dir).map(|(entry, _)| entry)
+    }
+
+    /// Returns the signed distances from the given point to each of the four edges of
+    /// this rectangle, along the axis perpendicular to the edge.
+    ///
+    /// A distance is positive when the point is outside of the rectangle past the
+    /// corresponding edge, and negative when the point is on the inner side of that
+    /// edge, so a point inside the rectangle has negative distances to all edges.
+    #[inline]
+    pub fn edge_distances(&self, p: TypedPoint2D<T, U>) -> TypedSideOffsets2D<T, U> {
+        TypedSideOffsets2D::new(
+            self.min_y() - p.y,
+            p.x - self.max_x(),
+            p.y - self.max_y(),
+            self.min_x() - p.x,
+        )
+    }
 }
 
 impl<T, U> TypedRect<T, U> {
@@ -370,6 +824,15 @@ impl<T: Copy + Div<T, Output=T>, U1, U2> Div<TypedScale<T, U1, U2>> for TypedRec
     }
 }
 
+impl<T: Copy + Mul<T, Output=T>, U> TypedRect<T, U> {
+    /// Returns this rectangle converted into the destination unit of `scale`, which
+    /// is equivalent to `*self * scale`. Both the origin and the size are scaled.
+    #[inline]
+    pub fn scale_by<Dst>(&self, scale: TypedScale<T, U, Dst>) -> TypedRect<T, Dst> {
+        *self * scale
+    }
+}
+
 impl<T: Copy, Unit> TypedRect<T, Unit> {
     /// Drop the units, preserving only the numeric value.
     pub fn to_untyped(&self) -> Rect<T> {
@@ -472,14 +935,82 @@ impl<T: NumCast + Copy, Unit> TypedRect<T, Unit> {
     }
 }
 
+impl<T: Float, Unit> TypedRect<T, Unit> {
+    /// Cast into an `i32` rectangle, truncating decimals if any and clamping each
+    /// field to the range of `i32`. NaN fields become zero.
+    ///
+    /// Unlike `to_i32`, this never panics, which makes it suitable for converting the
+    /// result of computations that may produce out of range values. As with `to_i32`,
+    /// consider whether to `round()`, `round_in()` or `round_out()` first.
+    pub fn to_i32_saturating(&self) -> TypedRect<i32, Unit> {
+        // Casts from floats with `as` saturate.
+        let cast = |v: T| v.to_f64().unwrap() as i32;
+        TypedRect::new(
+            TypedPoint2D::new(cast(self.origin.x), cast(self.origin.y)),
+            TypedSize2D::new(cast(self.size.width), cast(self.size.height)),
+        )
+    }
+
+    /// Cast into an `u32` rectangle, truncating decimals if any and clamping each
+    /// field to the range of `u32`, so that negative fields become zero. NaN fields
+    /// become zero.
+    ///
+    /// See `to_i32_saturating`.
+    pub fn to_u32_saturating(&self) -> TypedRect<u32, Unit> {
+        let cast = |v: T| v.to_f64().unwrap() as u32;
+        TypedRect::new(
+            TypedPoint2D::new(cast(self.origin.x), cast(self.origin.y)),
+            TypedSize2D::new(cast(self.size.width), cast(self.size.height)),
+        )
+    }
+}
+
+/// Returns the largest value smaller than `v`, or a value slightly smaller than `v`
+/// if `v` is zero or subnormal.
+fn largest_below<T: Float>(v: T) -> T {
+    // Subtracting half an epsilon relative to `v` rounds to the previous
+    // representable value, except for negative powers of two where it is a tie
+    // that rounds back to `v`, and where the spacing below is a full epsilon.
+    let half_epsilon = T::epsilon() / (T::one() + T::one());
+    for &eps in &[half_epsilon, T::epsilon()] {
+        let below = v - v.abs() * eps;
+        if below < v {
+            return below;
+        }
+    }
+    v - T::min_positive_value()
+}
+
 /// Shorthand for `TypedRect::new(TypedPoint2D::new(x, y), TypedSize2D::new(w, h))`.
+#[inline]
 pub fn rect<T: Copy, U>(x: T, y: T, w: T, h: T) -> TypedRect<T, U> {
     TypedRect::new(TypedPoint2D::new(x, y), TypedSize2D::new(w, h))
 }
 
+/// Returns the rectangle with the smallest area among those in `rects` that contain `p`,
+/// or `None` if none of them do.
+///
+/// This is useful to pick the innermost of nested hit regions. If several containing
+/// rectangles have the same area, the first one is returned.
+pub fn smallest_containing_rect<T, U, I>(p: TypedPoint2D<T, U>, rects: I) -> Option<TypedRect<T, U>>
+where T: Copy + Zero + PartialOrd + Add<T, Output=T> + Sub<T, Output=T> + Mul<T, Output=T>,
+      I: IntoIterator<Item=TypedRect<T, U>> {
+    let mut result: Option<TypedRect<T, U>> = None;
+    for r in rects {
+        if !r.contains(&p) {
+            continue;
+        }
+        match result {
+            Some(ref best) if best.size.area() <= r.size.area() => {}
+            _ => result = Some(r),
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
-    use point::Point2D;
+    use point::{Point2D, point2};
     use vector::vec2;
     use size::Size2D;
     use super::*;
@@ -619,6 +1150,42 @@ mod tests {
         assert!(r.contains_rect(&Rect::new(p, Size2D::zero())));
     }
 
+    #[test]
+    fn test_separation_vector() {
+        let r = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(10.0, 10.0));
+
+        // Overlapping by 2 on the x axis and by 10 on the y axis.
+        let s = Rect::new(Point2D::new(8.0, 0.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.separation_vector(&s), vec2(-2.0, 0.0));
+        assert_eq!(s.separation_vector(&r), vec2(2.0, 0.0));
+        assert_eq!(r.translate(&r.separation_vector(&s)).intersection(&s), None);
+
+        // Overlapping by 3 on the y axis.
+        let s = Rect::new(Point2D::new(1.0, -7.0), Size2D::new(5.0, 10.0));
+        assert_eq!(r.separation_vector(&s), vec2(0.0, 3.0));
+
+        // Touching and disjoint rectangles don't need to be separated.
+        let s = Rect::new(Point2D::new(10.0, 0.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.separation_vector(&s), vec2(0.0, 0.0));
+        let s = Rect::new(Point2D::new(20.0, 20.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.separation_vector(&s), vec2(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_gap_to() {
+        let r = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(10.0, 10.0));
+
+        let s = Rect::new(Point2D::new(15.0, 5.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.gap_to(&s), vec2(5.0, 0.0));
+
+        let s = Rect::new(Point2D::new(-30.0, -20.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.gap_to(&s), vec2(-20.0, -10.0));
+        assert_eq!(s.gap_to(&r), vec2(20.0, 10.0));
+
+        let s = Rect::new(Point2D::new(5.0, 5.0), Size2D::new(10.0, 10.0));
+        assert_eq!(r.gap_to(&s), vec2(0.0, 0.0));
+    }
+
     #[test]
     fn test_scale() {
         let p = Rect::new(Point2D::new(0u32, 0u32), Size2D::new(50u32, 40u32));
@@ -714,4 +1281,420 @@ mod tests {
             x += 0.1
         }
     }
+
+    #[test]
+    fn test_from_center_and_size() {
+        let r = Rect::from_center_and_size(Point2D::new(10.0, 20.0), Size2D::new(4.0, 6.0));
+        assert_eq!(r, Rect::new(Point2D::new(8.0, 17.0), Size2D::new(4.0, 6.0)));
+        assert_eq!(r.center(), Point2D::new(10.0, 20.0));
+
+        // Odd integer sizes put the extra unit on the right and bottom.
+        let r = Rect::from_center_and_size(Point2D::new(10, 10), Size2D::new(5, 3));
+        assert_eq!(r, Rect::new(Point2D::new(8, 9), Size2D::new(5, 3)));
+        assert_eq!(r.center(), Point2D::new(10, 10));
+    }
+
+    #[test]
+    fn test_flip_y_within() {
+        let r = Rect::new(Point2D::new(5.0, 10.0), Size2D::new(20.0, 30.0));
+        let flipped = r.flip_y_within(100.0);
+        assert_eq!(flipped, Rect::new(Point2D::new(5.0, 60.0), Size2D::new(20.0, 30.0)));
+        assert_eq!(flipped.flip_y_within(100.0), r);
+    }
+
+    #[test]
+    fn test_subtract() {
+        let r = Rect::new(Point2D::new(0, 0), Size2D::new(10, 10));
+
+        // No overlap.
+        let other = Rect::new(Point2D::new(10, 0), Size2D::new(5, 5));
+        assert_eq!(r.subtract(&other), vec![r]);
+
+        // Fully covered.
+        let other = Rect::new(Point2D::new(-1, -1), Size2D::new(12, 12));
+        assert!(r.subtract(&other).is_empty());
+
+        // Hole in the middle.
+        let other = Rect::new(Point2D::new(2, 3), Size2D::new(4, 5));
+        assert_eq!(r.subtract(&other), vec![
+            Rect::new(Point2D::new(0, 0), Size2D::new(10, 3)),
+            Rect::new(Point2D::new(0, 8), Size2D::new(10, 2)),
+            Rect::new(Point2D::new(0, 3), Size2D::new(2, 5)),
+            Rect::new(Point2D::new(6, 3), Size2D::new(4, 5)),
+        ]);
+
+        // Overlapping the right side.
+        let other = Rect::new(Point2D::new(5, -5), Size2D::new(10, 20));
+        assert_eq!(r.subtract(&other), vec![Rect::new(Point2D::new(0, 0), Size2D::new(5, 10))]);
+
+        // The pieces and the intersection cover the original area exactly once.
+        let other = Rect::new(Point2D::new(7, 4), Size2D::new(10, 2));
+        let pieces = r.subtract(&other);
+        let area: i32 = pieces.iter().map(|p| p.size.area()).sum();
+        assert_eq!(area + r.intersection(&other).unwrap().size.area(), r.size.area());
+        for (i, a) in pieces.iter().enumerate() {
+            for b in &pieces[i + 1..] {
+                assert!(!a.intersects(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_align_within() {
+        let container = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0));
+        let r = Rect::new(Point2D::new(3.0, 7.0), Size2D::new(10.0, 10.0));
+
+        let centered = r.align_within(&container, HorizontalAlign::Center, VerticalAlign::Center);
+        assert_eq!(centered, Rect::new(Point2D::new(45.0, 45.0), r.size));
+
+        let bottom_right = r.align_within(&container, HorizontalAlign::End, VerticalAlign::End);
+        assert_eq!(bottom_right, Rect::new(Point2D::new(90.0, 90.0), r.size));
+
+        let container = container.translate(&vec2(10.0, 20.0));
+        let top_left = r.align_within(&container, HorizontalAlign::Start, VerticalAlign::Start);
+        assert_eq!(top_left, Rect::new(Point2D::new(10.0, 20.0), r.size));
+    }
+
+    #[test]
+    fn test_edges() {
+        let r = Rect::new(Point2D::new(1, 2), Size2D::new(3, 4));
+        assert_eq!(r.edges(), [
+            (Point2D::new(1, 2), Point2D::new(4, 2)),
+            (Point2D::new(4, 2), Point2D::new(4, 6)),
+            (Point2D::new(4, 6), Point2D::new(1, 6)),
+            (Point2D::new(1, 6), Point2D::new(1, 2)),
+        ]);
+    }
+
+    #[test]
+    fn test_inflate_clamped() {
+        let container = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0));
+
+        let r = Rect::new(Point2D::new(0.0, 40.0), Size2D::new(10.0, 10.0));
+        let inflated = r.inflate_clamped(5.0, 5.0, &container);
+        assert_eq!(inflated, Rect::new(Point2D::new(0.0, 35.0), Size2D::new(15.0, 20.0)));
+        assert!(container.contains_rect(&inflated));
+
+        let r = Rect::new(Point2D::new(95.0, 95.0), Size2D::new(10.0, 10.0));
+        let inflated = r.inflate_clamped(2.0, 2.0, &container);
+        assert_eq!(inflated, Rect::new(Point2D::new(93.0, 93.0), Size2D::new(7.0, 7.0)));
+        assert!(container.contains_rect(&inflated));
+
+        let r = Rect::new(Point2D::new(40.0, 40.0), Size2D::new(20.0, 10.0));
+        let deflated = r.deflate_clamped(2.0, 10.0, &container);
+        assert_eq!(deflated.origin.x, 42.0);
+        assert_eq!(deflated.size, Size2D::new(16.0, 0.0));
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        use side_offsets::SideOffsets2D;
+
+        const ORIGIN: Point2D<i32> = Point2D::new(0, 0);
+        const SIZE: Size2D<i32> = Size2D::new(10, 20);
+        const RECT: Rect<i32> = Rect::new(ORIGIN, SIZE);
+        const MARGIN: SideOffsets2D<i32> = SideOffsets2D::new_all_same(5);
+
+        assert_eq!(RECT, Rect::new(Point2D::new(0, 0), Size2D::new(10, 20)));
+        assert_eq!(MARGIN, SideOffsets2D::new(5, 5, 5, 5));
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let r = Rect::new(Point2D::new(10.5, -3.0), Size2D::new(20.0, 15.5));
+        assert_eq!(
+            r.round_out_to_multiple(8.0),
+            Rect::new(Point2D::new(8.0, -8.0), Size2D::new(24.0, 24.0))
+        );
+        assert_eq!(
+            r.snap_to_grid(Point2D::new(1.0, 2.0), Size2D::new(4.0, 10.0)),
+            Rect::new(Point2D::new(9.0, -8.0), Size2D::new(24.0, 30.0))
+        );
+
+        // Adjacent rectangles still touch after snapping, whether or not their
+        // shared edge is on the grid.
+        for &edge in &[32.0, 37.3] {
+            let left = Rect::new(Point2D::new(3.7, 0.0), Size2D::new(edge - 3.7, 10.0));
+            let right = Rect::new(Point2D::new(edge, 0.0), Size2D::new(50.0, 10.0));
+            let left = left.round_out_to_multiple(16.0);
+            let right = right.round_out_to_multiple(16.0);
+            assert!(left.max_x() >= right.min_x());
+            if edge == 32.0 {
+                assert_eq!(left.max_x(), right.min_x());
+            }
+        }
+    }
+
+    #[test]
+    fn test_smallest_containing_rect() {
+        let outer: Rect<f32> = rect(0.0, 0.0, 100.0, 100.0);
+        let middle = rect(10.0, 10.0, 50.0, 50.0);
+        let inner = rect(20.0, 20.0, 10.0, 10.0);
+        let elsewhere = rect(70.0, 70.0, 5.0, 5.0);
+        let rects = vec![outer, inner, middle, elsewhere];
+
+        assert_eq!(smallest_containing_rect(point2(25.0, 25.0), rects.clone()), Some(inner));
+        assert_eq!(smallest_containing_rect(point2(15.0, 40.0), rects.clone()), Some(middle));
+        assert_eq!(smallest_containing_rect(point2(90.0, 5.0), rects.clone()), Some(outer));
+        assert_eq!(smallest_containing_rect(point2(200.0, 5.0), rects), None);
+        assert_eq!(smallest_containing_rect(point2(0.0, 0.0), Vec::<Rect<f32>>::new()), None);
+    }
+
+    #[test]
+    fn test_contains_with() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 20.0);
+        let left = point2(0.0, 5.0);
+        let top = point2(5.0, 0.0);
+        let right = point2(10.0, 5.0);
+        let bottom = point2(5.0, 20.0);
+        let inside = point2(5.0, 5.0);
+        let outside = point2(-1.0, 5.0);
+
+        for &(p, half_open, closed, open) in &[
+            (left, true, true, false),
+            (top, true, true, false),
+            (right, false, true, false),
+            (bottom, false, true, false),
+            (inside, true, true, true),
+            (outside, false, false, false),
+        ] {
+            assert_eq!(r.contains_with(p, EdgeInclusion::HalfOpen), half_open);
+            assert_eq!(r.contains_with(p, EdgeInclusion::HalfOpen), r.contains(&p));
+            assert_eq!(r.contains_with(p, EdgeInclusion::Closed), closed);
+            assert_eq!(r.contains_with(p, EdgeInclusion::Open), open);
+        }
+    }
+
+    /// A 24.8 fixed-point number, to check that the basic operations don't require `Float`.
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Fixed(i32);
+
+    impl Fixed {
+        fn new(v: i32) -> Self { Fixed(v << 8) }
+    }
+
+    impl Add for Fixed {
+        type Output = Fixed;
+        fn add(self, other: Fixed) -> Fixed { Fixed(self.0 + other.0) }
+    }
+
+    impl Sub for Fixed {
+        type Output = Fixed;
+        fn sub(self, other: Fixed) -> Fixed { Fixed(self.0 - other.0) }
+    }
+
+    impl Mul for Fixed {
+        type Output = Fixed;
+        fn mul(self, other: Fixed) -> Fixed { Fixed((self.0 * other.0) >> 8) }
+    }
+
+    impl ::num_traits::Zero for Fixed {
+        fn zero() -> Fixed { Fixed(0) }
+        fn is_zero(&self) -> bool { self.0 == 0 }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let f = Fixed::new;
+        let a: Rect<Fixed> = rect(f(0), f(0), f(10), f(10));
+        let b: Rect<Fixed> = rect(f(5), f(5), f(10), f(10));
+
+        assert_eq!(a.intersection(&b), Some(rect(f(5), f(5), f(5), f(5))));
+        assert_eq!(a.union(&b), rect(f(0), f(0), f(15), f(15)));
+        assert!(a.contains(&point2(f(9), f(0))));
+        assert!(!a.contains(&point2(f(10), f(0))));
+        assert!(a.intersects(&b));
+
+        let v = vec2::<Fixed, UnknownUnit>(f(2), f(3));
+        let w = vec2(Fixed(128), f(-1));
+        assert_eq!(v.dot(w), f(-2));
+        assert_eq!(v.min(w), vec2(Fixed(128), f(-1)));
+        assert_eq!(v.max(w), v);
+
+        let p: Point2D<Fixed> = point2(f(1), f(4));
+        assert_eq!(p.min(point2(f(2), f(3))), point2(f(1), f(3)));
+        assert_eq!(p.max(point2(f(2), f(3))), point2(f(2), f(4)));
+    }
+
+    #[test]
+    fn test_intersect_ray() {
+        let r: Rect<f32> = rect(0.0, 0.0, 10.0, 20.0);
+
+        // Passing through.
+        assert_eq!(r.intersect_ray(point2(-5.0, 10.0), vec2(1.0, 0.0)), Some((5.0, 15.0)));
+        assert_eq!(r.intersect_ray(point2(-10.0, -10.0), vec2(2.0, 2.0)), Some((5.0, 10.0)));
+        // Starting inside.
+        assert_eq!(r.intersect_ray(point2(5.0, 5.0), vec2(0.0, -1.0)), Some((0.0, 5.0)));
+        // Pointing away.
+        assert_eq!(r.intersect_ray(point2(-5.0, 10.0), vec2(-1.0, 0.0)), None);
+
+        // Grazing an edge and a corner.
+        assert_eq!(r.intersect_ray(point2(-5.0, 0.0), vec2(1.0, 0.0)), Some((5.0, 15.0)));
+        assert_eq!(r.intersect_ray(point2(-1.0, 1.0), vec2(1.0, -1.0)), Some((1.0, 1.0)));
+
+        // Parallel to a slab and outside of it.
+        assert_eq!(r.intersect_ray(point2(-5.0, 25.0), vec2(1.0, 0.0)), None);
+        assert_eq!(r.intersect_ray(point2(11.0, -5.0), vec2(0.0, 1.0)), None);
+        // Missing diagonally.
+        assert_eq!(r.intersect_ray(point2(-5.0, 10.0), vec2(1.0, 10.0)), None);
+    }
+
+    #[test]
+    fn test_guillotine_split() {
+        let free: Rect<i32> = rect(10, 20, 100, 100);
+        let (right, bottom) = free.guillotine_split(&rect(10, 20, 40, 30));
+        assert_eq!(right, rect(50, 20, 60, 100));
+        assert_eq!(bottom, rect(10, 50, 40, 70));
+
+        let (right, bottom) = free.guillotine_split(&rect(10, 20, 100, 30));
+        assert!(right.is_empty());
+        assert_eq!(bottom, rect(10, 50, 100, 70));
+
+        let (right, bottom) = free.guillotine_split(&free);
+        assert!(right.is_empty());
+        assert!(bottom.is_empty());
+
+        let (right, bottom) = free.guillotine_split(&rect(10, 20, 150, 150));
+        assert_eq!(right.size, Size2D::new(0, 100));
+        assert_eq!(bottom.size, Size2D::new(100, 0));
+    }
+
+    #[test]
+    fn test_approx_eq_relative() {
+        use approxeq::ApproxEq;
+
+        let a: Rect<f64> = rect(1.0e6, 2.0e6, 3.0e6, 4.0e6);
+        let b = rect(1.0e6 + 0.5, 2.0e6, 3.0e6, 4.0e6 - 0.5);
+        assert!(a.approx_eq_relative(&b, 1.0e-6));
+        assert!(!a.approx_eq_relative(&b, 1.0e-8));
+        assert!(!a.origin.approx_eq(&b.origin));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a: Rect<f32> = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(10.0, 20.0, 30.0, 0.0);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), rect(5.0, 10.0, 20.0, 5.0));
+        assert_eq!(a.lerp(b, 2.0), rect(20.0, 40.0, 50.0, -10.0));
+
+        let l = a.lerp(b, 0.25);
+        assert_eq!(l.bottom_right(), a.bottom_right().lerp(b.bottom_right(), 0.25));
+    }
+
+    #[test]
+    fn test_intersects_ray() {
+        let r: Rect<f32> = rect(10.0, 10.0, 10.0, 10.0);
+        assert_eq!(r.intersects_ray(point2(0.0, 15.0), vec2(2.0, 0.0)), Some(5.0));
+        assert_eq!(r.intersects_ray(point2(15.0, 30.0), vec2(0.0, -1.0)), Some(10.0));
+        assert_eq!(r.intersects_ray(point2(15.0, 15.0), vec2(1.0, 1.0)), Some(0.0));
+        assert_eq!(r.intersects_ray(point2(0.0, 15.0), vec2(-1.0, 0.0)), None);
+        assert_eq!(r.intersects_ray(point2(0.0, 5.0), vec2(1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_side_offsets_round_trip() {
+        use side_offsets::SideOffsets2D;
+
+        let outer: Rect<i32> = rect(0, 0, 100, 80);
+        let inner = rect(10, 5, 70, 60);
+        let offsets = SideOffsets2D::from_rect_difference(&outer, &inner);
+        assert_eq!(offsets, SideOffsets2D::new(5, 20, 15, 10));
+        assert_eq!(inner.outer_rect(offsets), outer);
+        assert_eq!(outer.inner_rect(offsets), inner);
+
+        assert_eq!(offsets.to_origin_offset(), vec2(-10, -5));
+        assert_eq!(offsets.to_size_delta(), Size2D::new(30, 20));
+        assert_eq!(inner.origin + offsets.to_origin_offset(), outer.origin);
+        assert_eq!(inner.size + offsets.to_size_delta(), outer.size);
+
+        // Inner rects that extend beyond the outer one give negative offsets.
+        let offsets = SideOffsets2D::from_rect_difference(&inner, &outer);
+        assert_eq!(offsets, SideOffsets2D::new(-5, -20, -15, -10));
+        assert_eq!(outer.outer_rect(offsets), inner);
+    }
+
+    #[test]
+    fn test_edge_distances() {
+        use side_offsets::SideOffsets2D;
+
+        let r: Rect<f32> = rect(10.0, 20.0, 30.0, 40.0);
+
+        let d = r.edge_distances(point2(7.0, 30.0));
+        assert_eq!(d.left, 3.0);
+        assert_eq!(d.right, -33.0);
+        assert_eq!(d.top, -10.0);
+        assert_eq!(d.bottom, -30.0);
+
+        let d = r.edge_distances(point2(15.0, 25.0));
+        assert_eq!(d, SideOffsets2D::new(-5.0, -25.0, -35.0, -5.0));
+
+        let d = r.edge_distances(point2(45.0, 65.0));
+        assert!(d.right > 0.0 && d.bottom > 0.0);
+        assert!(d.left < 0.0 && d.top < 0.0);
+    }
+
+    #[test]
+    fn test_to_int_saturating() {
+        let r: Rect<f32> = rect(1.5, -2.0, 10.0, 20.0);
+        assert_eq!(r.to_i32_saturating(), rect(1, -2, 10, 20));
+        assert_eq!(r.to_i32_saturating(), r.to_i32());
+        assert_eq!(r.to_u32_saturating(), rect(1, 0, 10, 20));
+
+        let r: Rect<f32> = rect(-1.0e20, 0.0, 1.0e20, f32::NAN);
+        assert_eq!(r.to_i32_saturating(), rect(i32::MIN, 0, i32::MAX, 0));
+        assert_eq!(r.to_u32_saturating(), rect(0, 0, u32::MAX, 0));
+
+        let r: Rect<f64> = rect(0.0, 1.0e300, 3.0e9, -1.0e300);
+        assert_eq!(r.to_i32_saturating(), rect(0, i32::MAX, i32::MAX, i32::MIN));
+        assert_eq!(r.to_u32_saturating(), rect(0, u32::MAX, 3_000_000_000, 0));
+    }
+
+    #[test]
+    fn test_half_size_and_radius() {
+        let r: Rect<f32> = rect(10.0, 20.0, 6.0, 8.0);
+        assert_eq!(r.half_size(), Size2D::new(3.0, 4.0));
+        assert_eq!(r.diagonal(), vec2(6.0, 8.0));
+        assert_eq!(r.radius(), 5.0);
+        assert_eq!(r.center() + r.half_size().to_vector(), r.bottom_right());
+
+        let r: Rect<i32> = rect(0, 0, 5, 7);
+        assert_eq!(r.half_size(), Size2D::new(2, 3));
+        assert_eq!(r.diagonal(), vec2(5, 7));
+    }
+
+    #[test]
+    fn test_clamp_point() {
+        let r: Rect<f32> = rect(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(r.clamp_point(point2(15.0, 25.0)), point2(15.0, 25.0));
+        assert_eq!(r.clamp_point(point2(0.0, 0.0)), point2(10.0, 20.0));
+
+        let p = r.clamp_point(point2(100.0, 45.0));
+        assert_eq!(p.x.to_bits(), 40.0f32.to_bits() - 1);
+        assert_eq!(p.y, 45.0);
+        assert!(r.contains(&p));
+
+        let p = r.clamp_point(point2(100.0, 100.0));
+        assert!(p.x < 40.0 && p.y < 60.0);
+        assert!(r.contains(&p));
+
+        let r: Rect<f64> = rect(-2.0, -1.0, 1.0, 1.0);
+        let p = r.clamp_point(point2(5.0, 5.0));
+        assert_eq!(p.x.to_bits(), (-1.0f64).to_bits() + 1);
+        assert_eq!(p.y, -f64::MIN_POSITIVE);
+        assert!(r.contains(&p));
+    }
+
+    #[test]
+    fn test_scale_by() {
+        enum CssPx {}
+        enum DevicePx {}
+
+        let r: TypedRect<f32, CssPx> = rect(10.0, 20.0, 100.0, 50.0);
+        let dpr: TypedScale<f32, CssPx, DevicePx> = TypedScale::new(1.5);
+        let device: TypedRect<f32, DevicePx> = r.scale_by(dpr);
+        assert_eq!(device, rect(15.0, 30.0, 150.0, 75.0));
+        assert_eq!(device, r * dpr);
+    }
 }
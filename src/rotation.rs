@@ -14,7 +14,7 @@ use std::ops::{Add, Neg, Mul, Sub, Div, AddAssign, SubAssign, MulAssign, DivAssi
 use std::marker::PhantomData;
 use trig::Trig;
 use {TypedPoint2D, TypedPoint3D, TypedVector2D, TypedVector3D, Vector3D, point2, point3, vec3};
-use {TypedTransform3D, TypedTransform2D, UnknownUnit};
+use {TypedTransform3D, TypedTransform2D, TypedRect, UnknownUnit};
 
 /// An angle in radians
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
@@ -71,6 +71,16 @@ where T: Rem<Output=T>
     pub fn signed(&self) -> Self {
         Angle::pi() - (Angle::pi() - *self).positive()
     }
+
+    /// Returns the shortest signed angle that rotates this angle onto `other`,
+    /// in the ]-PI..PI] range.
+    ///
+    /// Unlike `other - self`, the result wraps around, so the angle from 350
+    /// degrees to 10 degrees is 20 degrees rather than -340 degrees.
+    #[inline]
+    pub fn angle_to(&self, other: Self) -> Self {
+        (other - *self).signed()
+    }
 }
 
 impl<T> Angle<T>
@@ -125,6 +135,10 @@ impl<T: Clone + SubAssign<T>> SubAssign for Angle<T> {
     }
 }
 
+/// The ratio of two angles, for example the fraction of a full turn an angle covers.
+///
+/// No normalization is applied to either angle, so `Angle::radians(3.0 * PI) /
+/// Angle::radians(PI)` is `3.0`.
 impl<T: Clone + Div<T, Output=T>> Div<Angle<T>> for Angle<T> {
     type Output = T;
     #[inline]
@@ -264,6 +278,18 @@ where T: Copy + Clone +
     pub fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
         self.transform_point(&vector.to_point()).to_vector()
     }
+
+    /// Returns the smallest axis-aligned rectangle containing the given rectangle
+    /// rotated around the origin by this rotation.
+    #[inline]
+    pub fn transform_rect(&self, rect: &TypedRect<T, Src>) -> TypedRect<T, Dst> {
+        TypedRect::from_points(&[
+            self.transform_point(&rect.origin),
+            self.transform_point(&rect.top_right()),
+            self.transform_point(&rect.bottom_left()),
+            self.transform_point(&rect.bottom_right()),
+        ])
+    }
 }
 
 impl<T, Src, Dst> TypedRotation2D<T, Src, Dst>
@@ -382,6 +408,28 @@ where T: Float + ApproxEq<T>
         Self::quaternion(zero, zero, sin, cos)
     }
 
+    /// Returns the normalized axis and the angle of this rotation, such that
+    /// `around_axis(axis, angle)` yields an equivalent rotation.
+    ///
+    /// The angle is between zero and PI. When the angle is zero the axis is
+    /// arbitrary, in which case the x axis is returned.
+    pub fn to_axis_angle(&self) -> (TypedVector3D<T, Src>, Angle<T>) {
+        let zero = T::zero();
+        let one = T::one();
+        let two = one + one;
+
+        // q and -q represent the same rotation, pick the one with a positive real
+        // part so that the angle is not greater than PI.
+        let q = if self.r < zero { self.mul_s(-one) } else { *self };
+        let axis = TypedVector3D::new(q.i, q.j, q.k);
+        let sin_half = axis.length();
+        if sin_half.approx_eq(&zero) {
+            return (TypedVector3D::new(one, zero, zero), Angle::zero());
+        }
+
+        (axis / sin_half, Angle::radians(two * sin_half.atan2(q.r)))
+    }
+
     /// Creates a rotation from Euler angles.
     ///
     /// The rotations are applied in roll then pitch then yaw order.
@@ -424,7 +472,7 @@ where T: Float + ApproxEq<T>
     /// Returns a unit quaternion from this one.
     #[inline]
     pub fn normalize(&self) -> Self {
-        self.mul(T::one() / self.norm())
+        self.mul_s(T::one() / self.norm())
     }
 
     #[inline]
@@ -456,7 +504,7 @@ where T: Float + ApproxEq<T>
         // have opposite handed-ness and slerp won't take
         // the shorter path. Fix by reversing one quaternion.
         if dot < T::zero() {
-            r2 = r2.mul(-T::one());
+            r2 = r2.mul_s(-T::one());
             dot = -dot;
         }
 
@@ -467,9 +515,9 @@ where T: Float + ApproxEq<T>
         let theta = Float::acos(dot) * t;
 
         // r1 and r3 form an orthonormal basis.
-        let r3 = r2.sub(r1.mul(dot)).normalize();
+        let r3 = r2.sub(r1.mul_s(dot)).normalize();
         let (sin, cos) = Float::sin_cos(theta);
-        r1.mul(cos).add(r3.mul(sin))
+        r1.mul_s(cos).add(r3.mul_s(sin))
     }
 
     /// Basic Linear interpolation between this rotation and another rotation.
@@ -478,7 +526,7 @@ where T: Float + ApproxEq<T>
     #[inline]
     pub fn lerp(&self, other: &Self, t: T) -> Self {
         let one_t = T::one() - t;
-        self.mul(one_t).add(other.mul(t)).normalize()
+        self.mul_s(one_t).add(other.mul_s(t)).normalize()
     }
 
     /// Returns the given 3d point transformed by this rotation.
@@ -497,6 +545,21 @@ where T: Float + ApproxEq<T>
         )
     }
 
+    /// Transforms a slice of 3d points by this rotation, writing the results into `output`.
+    ///
+    /// This applies the quaternion directly to each point, without converting it into
+    /// a matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `output` don't have the same length.
+    pub fn rotate_point3d_slice(&self, points: &[TypedPoint3D<T, Src>], output: &mut [TypedPoint3D<T, Dst>]) {
+        assert_eq!(points.len(), output.len());
+        for (src, dst) in points.iter().zip(output.iter_mut()) {
+            *dst = self.rotate_point3d(src);
+        }
+    }
+
     /// Returns the given 2d point transformed by this rotation then projected on the xy plane.
     ///
     /// The input point must be use the unit Src, and the returned point has the unit Dst.
@@ -579,7 +642,7 @@ where T: Float + ApproxEq<T>
         other.pre_rotate(self)
     }
 
-    // add, sub and mul are used internally for intermediate computation but aren't public
+    // add, sub and mul_s are used internally for intermediate computation but aren't public
     // because they don't carry real semantic meanings (I think?).
 
     #[inline]
@@ -603,7 +666,7 @@ where T: Float + ApproxEq<T>
     }
 
     #[inline]
-    fn mul(&self, factor: T) -> Self {
+    fn mul_s(&self, factor: T) -> Self {
         Self::quaternion(
             self.i * factor,
             self.j * factor,
@@ -613,6 +676,22 @@ where T: Float + ApproxEq<T>
     }
 }
 
+/// Composes two rotations such that `a * b` applies `a` and then `b`.
+///
+/// This follows the order of the unit parameters, like multiplying `TypedScale`s,
+/// and is equivalent to `a.post_rotate(&b)` as well as to
+/// `a.to_transform().post_mul(&b.to_transform())`. In terms of Hamilton products
+/// of quaternions, this computes `b·a`.
+impl<T, A, B, C> Mul<TypedRotation3D<T, B, C>> for TypedRotation3D<T, A, B>
+where T: Float + ApproxEq<T>
+{
+    type Output = TypedRotation3D<T, A, C>;
+    #[inline]
+    fn mul(self, other: TypedRotation3D<T, B, C>) -> TypedRotation3D<T, A, C> {
+        self.post_rotate(&other)
+    }
+}
+
 impl<T: fmt::Debug, Src, Dst> fmt::Debug for TypedRotation3D<T, Src, Dst> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Quat({:?}*i + {:?}*j + {:?}*k + {:?})", self.i, self.j, self.k, self.r)
@@ -670,6 +749,29 @@ fn simple_rotation_2d() {
             &r90.transform_point(&point2(1.0, 2.0))
         )
     );
+
+    assert_eq!(r90.post_rotate(&ri).get_angle(), r90.get_angle());
+    assert_eq!(r90.pre_rotate(&ri).get_angle(), r90.get_angle());
+}
+
+#[test]
+fn rotation_2d_transform_rect() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+    use {Rect, rect};
+
+    let r: Rect<f32> = rect(0.0, 0.0, 2.0, 2.0);
+    let rotated = Rotation2D::radians(FRAC_PI_4).transform_rect(&r);
+    let diagonal = 8.0f32.sqrt();
+    assert!(rotated.origin.approx_eq(&point2(-diagonal / 2.0, 0.0)));
+    assert!(rotated.size.width.approx_eq(&diagonal));
+    assert!(rotated.size.height.approx_eq(&diagonal));
+
+    let rotation = Rotation2D::radians(FRAC_PI_2);
+    let r: Rect<f32> = rect(1.0, 2.0, 3.0, 4.0);
+    let rotated = rotation.transform_rect(&r);
+    assert!(rotated.origin.approx_eq(&point2(-6.0, 1.0)));
+    assert!(rotated.size.width.approx_eq(&4.0));
+    assert!(rotated.size.height.approx_eq(&3.0));
 }
 
 #[test]
@@ -717,6 +819,26 @@ fn pre_post() {
     assert!(!p1.approx_eq(&p3));
 }
 
+#[test]
+fn mul() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+    let r1 = Rotation3D::around_x(Angle::radians(FRAC_PI_2));
+    let r2 = Rotation3D::around_z(Angle::radians(FRAC_PI_4));
+
+    let p = point3(1.0, 2.0, 3.0);
+
+    let p1 = (r1 * r2).rotate_point3d(&p);
+    let p2 = r1.to_transform().post_mul(&r2.to_transform()).transform_point3d(&p);
+    assert!(p1.approx_eq(&p2));
+    assert!(p1.approx_eq(&r2.rotate_point3d(&r1.rotate_point3d(&p))));
+
+    // Rotations don't commute.
+    assert!(!(r2 * r1).rotate_point3d(&p).approx_eq(&p1));
+
+    assert!((r1 * Rotation3D::identity()).approx_eq(&r1));
+    assert!((Rotation3D::identity() * r1).approx_eq(&r1));
+}
+
 #[test]
 fn to_transform3d() {
     use std::f32::consts::{PI, FRAC_PI_2};
@@ -749,6 +871,22 @@ fn to_transform3d() {
     }
 }
 
+#[test]
+fn rotate_point3d() {
+    use std::f32::consts::FRAC_PI_2;
+    let r = Rotation3D::around_z(Angle::radians(FRAC_PI_2));
+
+    assert!(r.rotate_point3d(&point3(1.0, 0.0, 0.0)).approx_eq(&point3(0.0, 1.0, 0.0)));
+
+    let points = [point3(1.0, 0.0, 0.0), point3(0.0, 1.0, 0.0), point3(1.0, 2.0, 3.0)];
+    let mut rotated = [point3(0.0, 0.0, 0.0); 3];
+    r.rotate_point3d_slice(&points, &mut rotated);
+
+    assert!(rotated[0].approx_eq(&point3(0.0, 1.0, 0.0)));
+    assert!(rotated[1].approx_eq(&point3(-1.0, 0.0, 0.0)));
+    assert!(rotated[2].approx_eq(&point3(-2.0, 1.0, 3.0)));
+}
+
 #[test]
 fn slerp() {
     let q1 = Rotation3D::quaternion(1.0, 0.0, 0.0, 0.0);
@@ -792,6 +930,33 @@ fn around_axis() {
     assert!(r3.rotate_point3d(&point3(1.0, 0.0, 0.0)).approx_eq(&point3(-0.58071821,  0.81401868, -0.01182979)));
 }
 
+#[test]
+fn to_axis_angle() {
+    use std::f32::consts::{PI, FRAC_PI_2};
+
+    let (axis, angle) = Rotation3D::<f32>::identity().to_axis_angle();
+    assert_eq!(axis, vec3(1.0, 0.0, 0.0));
+    assert_eq!(angle.get(), 0.0);
+
+    let (axis, angle) = Rotation3D::around_y(Angle::radians(FRAC_PI_2)).to_axis_angle();
+    assert!(axis.approx_eq(&vec3(0.0, 1.0, 0.0)));
+    assert!(angle.get().approx_eq(&FRAC_PI_2));
+
+    // Rotating by -PI/2 around z is the same as rotating by PI/2 around -z.
+    let (axis, angle) = Rotation3D::around_z(Angle::radians(-FRAC_PI_2)).to_axis_angle();
+    assert!(axis.approx_eq(&vec3(0.0, 0.0, -1.0)));
+    assert!(angle.get().approx_eq(&FRAC_PI_2));
+
+    let r = Rotation3D::around_axis(vec3(0.5, 1.0, 2.0), Angle::radians(2.291288));
+    let (axis, angle) = r.to_axis_angle();
+    assert!(axis.approx_eq(&vec3(0.5, 1.0, 2.0).normalize()));
+    assert!(angle.get().approx_eq(&2.291288));
+    assert!(Rotation3D::around_axis(axis, angle).approx_eq(&r));
+
+    let (_, angle) = Rotation3D::around_x(Angle::radians(PI)).to_axis_angle();
+    assert!(angle.get().approx_eq(&PI));
+}
+
 #[test]
 fn from_euler() {
     use std::f32::consts::FRAC_PI_2;
@@ -858,3 +1023,25 @@ fn wrap_angles() {
     assert!(Angle::radians(-PI).signed().radians.approx_eq(&PI));
     assert!(Angle::radians(PI).signed().radians.approx_eq(&PI));
 }
+
+#[test]
+fn angle_to() {
+    use std::f32::consts::PI;
+
+    assert!(Angle::degrees(350.0).angle_to(Angle::degrees(10.0)).to_degrees().approx_eq_eps(&20.0, &1e-4));
+    assert!(Angle::degrees(10.0).angle_to(Angle::degrees(350.0)).to_degrees().approx_eq_eps(&-20.0, &1e-4));
+    assert!(Angle::degrees(170.0).angle_to(Angle::degrees(-170.0)).to_degrees().approx_eq_eps(&20.0, &1e-4));
+    assert!(Angle::degrees(-170.0).angle_to(Angle::degrees(170.0)).to_degrees().approx_eq_eps(&-20.0, &1e-4));
+    assert!(Angle::radians(0.0).angle_to(Angle::radians(PI)).radians.approx_eq(&PI));
+    assert!(Angle::radians(PI).angle_to(Angle::radians(0.0)).radians.approx_eq(&PI));
+    assert!(Angle::radians(1.0).angle_to(Angle::radians(1.0)).radians.approx_eq(&0.0));
+}
+
+#[test]
+fn angle_ratio() {
+    use std::f32::consts::PI;
+
+    assert!((Angle::radians(3.0 * PI) / Angle::radians(PI)).approx_eq(&3.0));
+    assert!((Angle::degrees(90.0) / Angle::two_pi()).approx_eq(&0.25));
+    assert!((Angle::degrees(-45.0) / Angle::degrees(90.0)).approx_eq(&-0.5));
+}
@@ -15,7 +15,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::ops::{Add, Mul, Sub, Div, Neg};
 use std::marker::PhantomData;
-use {TypedRect, TypedSize2D, TypedPoint2D, TypedVector2D};
+use {Length, TypedRect, TypedSize2D, TypedPoint2D, TypedVector2D};
 
 /// A scaling factor between two different units of measurement.
 ///
@@ -36,6 +36,36 @@ use {TypedRect, TypedSize2D, TypedPoint2D, TypedVector2D};
 /// let one_foot: Length<f32, Inch> = Length::new(12.0);
 /// let one_foot_in_mm: Length<f32, Mm> = one_foot * mm_per_inch;
 /// ```
+///
+/// Scales can be chained through an intermediate unit by multiplying them, which
+/// multiplies the factors:
+///
+/// ```rust
+/// use euclid::TypedScale;
+/// enum CssPixel {};
+/// enum LayoutPixel {};
+/// enum DevicePixel {};
+///
+/// let css_to_layout: TypedScale<f32, CssPixel, LayoutPixel> = TypedScale::new(1.5);
+/// let layout_to_device: TypedScale<f32, LayoutPixel, DevicePixel> = TypedScale::new(2.0);
+///
+/// let css_to_device: TypedScale<f32, CssPixel, DevicePixel> = css_to_layout * layout_to_device;
+/// assert_eq!(css_to_device.get(), 3.0);
+/// ```
+///
+/// The intermediate units must match:
+///
+/// ```compile_fail
+/// use euclid::TypedScale;
+/// enum CssPixel {};
+/// enum LayoutPixel {};
+/// enum DevicePixel {};
+///
+/// let css_to_layout: TypedScale<f32, CssPixel, LayoutPixel> = TypedScale::new(1.5);
+/// let css_to_device: TypedScale<f32, CssPixel, DevicePixel> = TypedScale::new(3.0);
+///
+/// let _ = css_to_layout * css_to_device;
+/// ```
 #[repr(C)]
 pub struct TypedScale<T, Src, Dst>(pub T, PhantomData<(Src, Dst)>);
 
@@ -53,7 +83,7 @@ impl<T, Src, Dst> Serialize for TypedScale<T, Src, Dst> where T: Serialize {
 }
 
 impl<T, Src, Dst> TypedScale<T, Src, Dst> {
-    pub fn new(x: T) -> TypedScale<T, Src, Dst> {
+    pub const fn new(x: T) -> TypedScale<T, Src, Dst> {
         TypedScale(x, PhantomData)
     }
 }
@@ -64,6 +94,26 @@ impl<T: Clone, Src, Dst> TypedScale<T, Src, Dst> {
     }
 }
 
+impl<T: One, Src, Dst> TypedScale<T, Src, Dst> {
+    /// Creates the identity scale, with a factor of one.
+    #[inline]
+    pub fn identity() -> Self {
+        TypedScale::new(T::one())
+    }
+}
+
+impl<T: Clone + Div<T, Output=T>, Src, Dst> TypedScale<T, Src, Dst> {
+    /// Creates the scale that converts `src` into `dst`, that is `dst / src`, from two
+    /// lengths measuring the same quantity in the source and destination units.
+    ///
+    /// For floating point types, a zero `src` gives an infinite scale (or NaN if `dst`
+    /// is zero as well). For integer types it panics like any division by zero.
+    #[inline]
+    pub fn from_lengths(src: Length<T, Src>, dst: Length<T, Dst>) -> Self {
+        TypedScale::new(dst.get() / src.get())
+    }
+}
+
 impl<T: Clone + One + Div<T, Output=T>, Src, Dst> TypedScale<T, Src, Dst> {
     /// The inverse TypedScale (1.0 / self).
     pub fn inv(&self) -> TypedScale<T, Dst, Src> {
@@ -209,4 +259,27 @@ mod tests {
         assert_eq!(a.clone() + b.clone(), TypedScale::new(5));
         assert_eq!(a - b, TypedScale::new(-1));
     }
+
+    #[test]
+    fn test_identity() {
+        let mm_per_inch: TypedScale<f32, Inch, Mm> = TypedScale::new(25.4);
+        let identity: TypedScale<f32, Mm, Mm> = TypedScale::identity();
+        assert!(identity.is_identity());
+        assert_eq!(mm_per_inch * identity, mm_per_inch);
+        assert_eq!(TypedScale::<f32, Inch, Inch>::identity() * mm_per_inch, mm_per_inch);
+    }
+
+    #[test]
+    fn test_from_lengths() {
+        use Length;
+
+        let one_inch: Length<f32, Inch> = Length::new(1.0);
+        let in_mm: Length<f32, Mm> = Length::new(25.4);
+        let mm_per_inch: TypedScale<f32, Inch, Mm> = TypedScale::from_lengths(one_inch, in_mm);
+        assert_eq!(mm_per_inch, TypedScale::new(25.4));
+        assert_eq!(Length::<f32, Inch>::new(2.0) * mm_per_inch, Length::new(50.8));
+
+        let zero: Length<f32, Inch> = Length::new(0.0);
+        assert_eq!(TypedScale::from_lengths(zero, in_mm).get(), f32::INFINITY);
+    }
 }
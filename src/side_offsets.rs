@@ -13,8 +13,11 @@
 use super::UnknownUnit;
 use length::Length;
 use num::Zero;
+use rect::TypedRect;
+use size::TypedSize2D;
+use vector::TypedVector2D;
 use std::fmt;
-use std::ops::Add;
+use std::ops::{Add, Neg, Sub};
 use std::marker::PhantomData;
 
 /// A group of side offsets, which correspond to top/left/bottom/right for borders, padding,
@@ -40,7 +43,7 @@ pub type SideOffsets2D<T> = TypedSideOffsets2D<T, UnknownUnit>;
 
 impl<T: Copy, U> TypedSideOffsets2D<T, U> {
     /// Constructor taking a scalar for each side.
-    pub fn new(top: T, right: T, bottom: T, left: T) -> Self {
+    pub const fn new(top: T, right: T, bottom: T, left: T) -> Self {
         TypedSideOffsets2D {
             top: top,
             right: right,
@@ -71,7 +74,7 @@ impl<T: Copy, U> TypedSideOffsets2D<T, U> {
     pub fn left_typed(&self) -> Length<T, U> { Length::new(self.left) }
 
     /// Constructor setting the same value to all sides, taking a scalar value directly.
-    pub fn new_all_same(all: T) -> Self {
+    pub const fn new_all_same(all: T) -> Self {
         TypedSideOffsets2D::new(all, all, all, all)
     }
 
@@ -99,6 +102,41 @@ impl<T, U> TypedSideOffsets2D<T, U> where T: Add<T, Output=T> + Copy {
     }
 }
 
+impl<T: Copy, U> TypedSideOffsets2D<T, U> {
+    /// Returns the offset to apply to the origin of a rectangle to grow it by these
+    /// offsets, that is `(-left, -top)`.
+    ///
+    /// See also `to_size_delta` and `TypedRect::outer_rect`.
+    pub fn to_origin_offset(&self) -> TypedVector2D<T, U> where T: Neg<Output=T> {
+        TypedVector2D::new(-self.left, -self.top)
+    }
+
+    /// Returns the change in size of a rectangle grown by these offsets, that is
+    /// `(left + right, top + bottom)`.
+    pub fn to_size_delta(&self) -> TypedSize2D<T, U> where T: Add<T, Output=T> {
+        TypedSize2D::new(self.horizontal(), self.vertical())
+    }
+
+    /// Constructor computing the offsets between the sides of `outer` and the sides
+    /// of `inner`, such that `inner.outer_rect(offsets)` is `outer` and
+    /// `outer.inner_rect(offsets)` is `inner`.
+    ///
+    /// The offsets are negative on the sides where `inner` extends beyond `outer`.
+    pub fn from_rect_difference(outer: &TypedRect<T, U>, inner: &TypedRect<T, U>) -> Self
+    where T: Add<T, Output=T> + Sub<T, Output=T> {
+        let outer_max_x = outer.origin.x + outer.size.width;
+        let outer_max_y = outer.origin.y + outer.size.height;
+        let inner_max_x = inner.origin.x + inner.size.width;
+        let inner_max_y = inner.origin.y + inner.size.height;
+        TypedSideOffsets2D::new(
+            inner.origin.y - outer.origin.y,
+            outer_max_x - inner_max_x,
+            outer_max_y - inner_max_y,
+            inner.origin.x - outer.origin.x,
+        )
+    }
+}
+
 impl<T, U> Add for TypedSideOffsets2D<T, U> where T : Copy + Add<T, Output=T> {
     type Output = Self;
     fn add(self, other: Self) -> Self {
@@ -10,10 +10,11 @@
 use super::UnknownUnit;
 use length::Length;
 use scale::TypedScale;
+use side_offsets::TypedSideOffsets2D;
 use vector::{TypedVector2D, vec2};
 use num::*;
 
-use num_traits::{NumCast, Signed};
+use num_traits::{Float, NumCast, PrimInt, Signed, Unsigned};
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
 use std::marker::PhantomData;
@@ -45,7 +46,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedSize2D<T, U> {
 
 impl<T, U> TypedSize2D<T, U> {
     /// Constructor taking scalar values.
-    pub fn new(width: T, height: T) -> Self {
+    pub const fn new(width: T, height: T) -> Self {
         TypedSize2D {
             width: width,
             height: height,
@@ -102,6 +103,41 @@ impl<T: Copy + Sub<T, Output=T>, U> Sub for TypedSize2D<T, U> {
     }
 }
 
+impl<T: Copy + Add<T, Output=T>, U> Add<TypedVector2D<T, U>> for TypedSize2D<T, U> {
+    type Output = Self;
+    /// Grows the width by the vector's x component and the height by its y component.
+    fn add(self, other: TypedVector2D<T, U>) -> Self {
+        TypedSize2D::new(self.width + other.x, self.height + other.y)
+    }
+}
+
+impl<T: Copy + Sub<T, Output=T>, U> Sub<TypedVector2D<T, U>> for TypedSize2D<T, U> {
+    type Output = Self;
+    /// Shrinks the width by the vector's x component and the height by its y component.
+    fn sub(self, other: TypedVector2D<T, U>) -> Self {
+        TypedSize2D::new(self.width - other.x, self.height - other.y)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, U> TypedSize2D<T, U> {
+    /// Returns this size grown by the horizontal and vertical totals of `offsets`,
+    /// for example to compute a border-box size from a content-box size.
+    #[inline]
+    pub fn add_offsets(&self, offsets: &TypedSideOffsets2D<T, U>) -> Self {
+        TypedSize2D::new(self.width + offsets.horizontal(), self.height + offsets.vertical())
+    }
+
+    /// Returns this size shrunk by the horizontal and vertical totals of `offsets`,
+    /// for example to compute a content-box size from a border-box size.
+    ///
+    /// The result is negative if the offsets are larger than this size.
+    #[inline]
+    pub fn sub_offsets(&self, offsets: &TypedSideOffsets2D<T, U>) -> Self
+    where T: Sub<T, Output=T> {
+        TypedSize2D::new(self.width - offsets.horizontal(), self.height - offsets.vertical())
+    }
+}
+
 impl<T: Copy + Clone + Mul<T>, U> TypedSize2D<T, U> {
     pub fn area(&self) -> T::Output { self.width * self.height }
 }
@@ -121,6 +157,105 @@ where T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
     }
 }
 
+impl<T: Float, U> TypedSize2D<T, U> {
+    /// Uniformly scales this size so that it fits within `bounds` while
+    /// preserving the aspect ratio ("contain" semantics).
+    ///
+    /// The result touches `bounds` on at least one axis and does not exceed
+    /// it on either.
+    ///
+    /// If this size has a zero width or height, it has no aspect ratio to
+    /// preserve and is returned unchanged.
+    #[inline]
+    pub fn fit_within(&self, bounds: Self) -> Self {
+        if self.width == T::zero() || self.height == T::zero() {
+            return *self;
+        }
+        let scale = (bounds.width / self.width).min(bounds.height / self.height);
+        size2(self.width * scale, self.height * scale)
+    }
+
+    /// Uniformly scales this size so that it covers `bounds` while
+    /// preserving the aspect ratio ("cover" semantics).
+    ///
+    /// The result touches `bounds` on at least one axis and overflows it on
+    /// the other.
+    ///
+    /// If this size has a zero width or height, it has no aspect ratio to
+    /// preserve and is returned unchanged.
+    #[inline]
+    pub fn fill(&self, bounds: Self) -> Self {
+        if self.width == T::zero() || self.height == T::zero() {
+            return *self;
+        }
+        let scale = (bounds.width / self.width).max(bounds.height / self.height);
+        size2(self.width * scale, self.height * scale)
+    }
+}
+
+impl<T: Copy + PartialOrd, U> TypedSize2D<T, U> {
+    /// Returns the per-dimension minimum of this size and another size.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        size2(
+            if other.width < self.width { other.width } else { self.width },
+            if other.height < self.height { other.height } else { self.height },
+        )
+    }
+
+    /// Returns the per-dimension maximum of this size and another size.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        size2(
+            if other.width > self.width { other.width } else { self.width },
+            if other.height > self.height { other.height } else { self.height },
+        )
+    }
+
+    /// Clamps each dimension of this size between the respective dimensions
+    /// of `min` and `max`. The aspect ratio is not preserved.
+    ///
+    /// If a dimension of `min` is greater than the one of `max`, `min` wins, like
+    /// CSS's `min-width` and `min-height` do over `max-width` and `max-height`.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.min(max).max(min)
+    }
+}
+
+impl<T: PrimInt + Unsigned, U> TypedSize2D<T, U> {
+    /// Rounds each dimension up to the next power of two.
+    ///
+    /// Dimensions that are already a power of two are left unchanged, and zero becomes one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension is greater than the largest power of two representable
+    /// by `T`, in both debug and release builds.
+    #[inline]
+    pub fn next_power_of_two(&self) -> Self {
+        fn next<T: PrimInt>(v: T) -> T {
+            let one = T::one();
+            if v <= one {
+                return one;
+            }
+            let bits = T::zero().count_zeros();
+            let shift = bits - (v - one).leading_zeros();
+            assert!(shift < bits, "next_power_of_two overflowed");
+            one << shift as usize
+        }
+        size2(next(self.width), next(self.height))
+    }
+
+    /// Returns the number of levels in a full mipmap chain for a texture of this size,
+    /// which is `floor(log2(max(width, height))) + 1`, or zero for an empty size.
+    #[inline]
+    pub fn mip_level_count(&self) -> u32 {
+        let max_side = if self.width > self.height { self.width } else { self.height };
+        T::zero().count_zeros() - max_side.leading_zeros()
+    }
+}
+
 impl<T: Zero + PartialOrd, U> TypedSize2D<T, U> {
     pub fn is_empty_or_negative(&self) -> bool {
         let zero = T::zero();
@@ -178,6 +313,15 @@ impl<T: Copy + Div<T, Output=T>, U1, U2> Div<TypedScale<T, U1, U2>> for TypedSiz
     }
 }
 
+impl<T: Copy + Mul<T, Output=T>, U> TypedSize2D<T, U> {
+    /// Returns this size converted into the destination unit of `scale`, which is
+    /// equivalent to `*self * scale`.
+    #[inline]
+    pub fn scale_by<Dst>(&self, scale: TypedScale<T, U, Dst>) -> TypedSize2D<T, Dst> {
+        *self * scale
+    }
+}
+
 impl<T: Copy, U> TypedSize2D<T, U> {
     /// Returns self.width as a Length carrying the unit.
     #[inline]
@@ -204,6 +348,8 @@ impl<T: Copy, U> TypedSize2D<T, U> {
     }
 }
 
+impl_byte_conversions!(TypedSize2D, 2, {width, height});
+
 impl<T: NumCast + Copy, Unit> TypedSize2D<T, Unit> {
     /// Cast from one numeric representation to another, preserving the units.
     ///
@@ -269,6 +415,7 @@ where T: Signed {
 }
 
 /// Shorthand for `TypedSize2D::new(w, h)`.
+#[inline]
 pub fn size2<T, U>(w: T, h: T) -> TypedSize2D<T, U> {
     TypedSize2D::new(w, h)
 }
@@ -320,4 +467,107 @@ mod size2d {
         let p = Size2D::new(1.5, 2.0);
         assert_eq!(p.area(), 3.0);
     }
+
+    #[test]
+    pub fn test_fit_within_and_fill() {
+        let image = Size2D::new(200.0, 100.0);
+        let bounds = Size2D::new(100.0, 100.0);
+        assert_eq!(image.fit_within(bounds), Size2D::new(100.0, 50.0));
+        assert_eq!(image.fill(bounds), Size2D::new(200.0, 100.0));
+
+        let bounds = Size2D::new(400.0, 400.0);
+        assert_eq!(image.fit_within(bounds), Size2D::new(400.0, 200.0));
+        assert_eq!(image.fill(bounds), Size2D::new(800.0, 400.0));
+
+        // Degenerate sizes are returned unchanged instead of becoming NaN.
+        let bounds = Size2D::new(100.0, 100.0);
+        let empty = Size2D::new(0.0, 0.0);
+        assert_eq!(empty.fit_within(bounds), empty);
+        assert_eq!(empty.fill(bounds), empty);
+        let line = Size2D::new(0.0, 50.0);
+        assert_eq!(line.fit_within(bounds), line);
+        assert_eq!(line.fill(bounds), line);
+    }
+
+    #[test]
+    pub fn test_clamp() {
+        let min = Size2D::new(10.0, 10.0);
+        let max = Size2D::new(100.0, 50.0);
+        assert_eq!(Size2D::new(5.0, 70.0).clamp(min, max), Size2D::new(10.0, 50.0));
+        assert_eq!(Size2D::new(20.0, 30.0).clamp(min, max), Size2D::new(20.0, 30.0));
+
+        // The minimum wins over the maximum.
+        let min = Size2D::new(10, 60);
+        let max = Size2D::new(100, 50);
+        assert_eq!(Size2D::new(200, 55).clamp(min, max), Size2D::new(100, 60));
+        assert_eq!(Size2D::new(5, 40).clamp(min, max), Size2D::new(10, 60));
+    }
+
+    #[test]
+    pub fn test_min_max() {
+        let a = Size2D::new(1, 20);
+        let b = Size2D::new(10, 2);
+        assert_eq!(a.min(b), Size2D::new(1, 2));
+        assert_eq!(a.max(b), Size2D::new(10, 20));
+    }
+
+    #[test]
+    pub fn test_next_power_of_two() {
+        assert_eq!(Size2D::new(100u32, 200).next_power_of_two(), Size2D::new(128, 256));
+        assert_eq!(Size2D::new(64u32, 65).next_power_of_two(), Size2D::new(64, 128));
+        assert_eq!(Size2D::new(0u8, 1).next_power_of_two(), Size2D::new(1, 1));
+        assert_eq!(Size2D::new(128u8, 1).next_power_of_two(), Size2D::new(128, 1));
+        assert_eq!(Size2D::new(3u64, 1 << 40).next_power_of_two(), Size2D::new(4, 1 << 40));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_next_power_of_two_overflow() {
+        Size2D::new(129u8, 1).next_power_of_two();
+    }
+
+    #[test]
+    pub fn test_mip_level_count() {
+        assert_eq!(Size2D::new(256u32, 1).mip_level_count(), 9);
+        assert_eq!(Size2D::new(100u32, 300).mip_level_count(), 9);
+        assert_eq!(Size2D::new(1u16, 1).mip_level_count(), 1);
+        assert_eq!(Size2D::new(0u32, 0).mip_level_count(), 0);
+    }
+
+    #[test]
+    pub fn test_add_sub_vector() {
+        use vector::vec2;
+
+        let s = Size2D::new(10.0, 20.0);
+        assert_eq!(s + vec2(1.0, -2.0), Size2D::new(11.0, 18.0));
+        assert_eq!(s - vec2(1.0, -2.0), Size2D::new(9.0, 22.0));
+        assert_eq!(s + Size2D::new(1.0, 2.0) - vec2(1.0, 2.0), s);
+        assert_eq!(s * 2.0 / 4.0, Size2D::new(5.0, 10.0));
+    }
+
+    #[test]
+    pub fn test_offsets() {
+        use side_offsets::SideOffsets2D;
+
+        let border_box = Size2D::new(100, 50);
+        let padding = SideOffsets2D::new(1, 2, 3, 4);
+        assert_eq!(border_box.sub_offsets(&padding), Size2D::new(94, 46));
+        assert_eq!(border_box.sub_offsets(&padding).add_offsets(&padding), border_box);
+        assert_eq!(Size2D::new(4, 2).sub_offsets(&padding), Size2D::new(-2, -2));
+    }
+
+    #[test]
+    pub fn test_scale_by() {
+        use scale::TypedScale;
+        use super::TypedSize2D;
+
+        enum CssPx {}
+        enum DevicePx {}
+
+        let size: TypedSize2D<f32, CssPx> = TypedSize2D::new(100.0, 50.0);
+        let dpr: TypedScale<f32, CssPx, DevicePx> = TypedScale::new(2.0);
+        let device: TypedSize2D<f32, DevicePx> = size.scale_by(dpr);
+        assert_eq!(device, TypedSize2D::new(200.0, 100.0));
+        assert_eq!(device, size * dpr);
+    }
 }
@@ -0,0 +1,181 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A trait to write code that is generic over the type of 2d transformation.
+
+use approxeq::ApproxEq;
+use num::{One, Zero};
+use num_traits::Float;
+use point::TypedPoint2D;
+use rotation::TypedRotation2D;
+use scale::TypedScale;
+use std::ops::{Add, Mul, Div, Sub, Neg};
+use transform2d::TypedTransform2D;
+use transform3d::TypedTransform3D;
+use trig::Trig;
+use vector::TypedVector2D;
+
+/// A transformation from 2d points and vectors in the `Src` space to the `Dst` space.
+///
+/// This is implemented by `TypedTransform2D`, `TypedTransform3D` (which projects onto
+/// the 2d plane, see `TypedTransform3D::transform_point2d`), `TypedScale`,
+/// `TypedRotation2D` and the translation types:
+///
+/// ```rust
+/// use euclid::{Transform, TypedScale, TypedTransform2D, point2};
+/// enum Local {};
+/// enum World {};
+///
+/// fn apply<X: Transform<f32, Local, World>>(transform: &X) -> (f32, f32) {
+///     let p = transform.transform_point(&point2(1.0, 2.0));
+///     (p.x, p.y)
+/// }
+///
+/// let scale: TypedScale<f32, Local, World> = TypedScale::new(2.0);
+/// let translation: TypedTransform2D<f32, Local, World> = TypedTransform2D::create_translation(1.0, 1.0);
+/// assert_eq!(apply(&scale), (2.0, 4.0));
+/// assert_eq!(apply(&translation), (2.0, 3.0));
+/// ```
+pub trait Transform<T, Src, Dst> {
+    /// Returns the given point transformed by this transformation.
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst>;
+
+    /// Returns the given vector transformed by this transformation.
+    ///
+    /// Vectors are not affected by translations.
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst>;
+}
+
+impl<T, Src, Dst> Transform<T, Src, Dst> for TypedTransform2D<T, Src, Dst>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Sub<T, Output=T> +
+         Trig +
+         PartialOrd +
+         One + Zero {
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        TypedTransform2D::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        TypedTransform2D::transform_vector(self, vector)
+    }
+}
+
+impl<T, Src, Dst> Transform<T, Src, Dst> for TypedTransform3D<T, Src, Dst>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Sub<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Neg<Output=T> +
+         PartialOrd +
+         Trig +
+         One + Zero {
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        self.transform_point2d(point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        self.transform_vector2d(vector)
+    }
+}
+
+impl<T, Src, Dst> Transform<T, Src, Dst> for TypedScale<T, Src, Dst>
+where T: Copy + Clone +
+         Mul<T, Output=T> +
+         Neg<Output=T> +
+         PartialEq +
+         One {
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        TypedScale::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        TypedScale::transform_vector(self, vector)
+    }
+}
+
+impl<T, Src, Dst> Transform<T, Src, Dst> for TypedRotation2D<T, Src, Dst>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Sub<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Neg<Output=T> +
+         ApproxEq<T> +
+         PartialOrd +
+         Float +
+         One + Zero {
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        TypedRotation2D::transform_point(self, point)
+    }
+
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        TypedRotation2D::transform_vector(self, vector)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transform;
+    use approxeq::ApproxEq;
+    use {Angle, TypedRotation2D, TypedScale, TypedTransform2D, TypedTransform3D};
+    use {TypedPoint2D, TypedVector2D, point2, vec2, vec3};
+    use std::f32::consts::FRAC_PI_2;
+
+    enum Src {}
+    enum Dst {}
+
+    fn apply<X: Transform<f32, Src, Dst>>(
+        transform: &X,
+    ) -> (TypedPoint2D<f32, Dst>, TypedVector2D<f32, Dst>) {
+        (
+            transform.transform_point(&point2(1.0, 2.0)),
+            transform.transform_vector(&vec2(1.0, 2.0)),
+        )
+    }
+
+    #[test]
+    fn test_transform_2d() {
+        let t: TypedTransform2D<f32, Src, Dst> = TypedTransform2D::create_translation(10.0, 20.0);
+        assert_eq!(apply(&t), (point2(11.0, 22.0), vec2(1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_transform_3d() {
+        let t: TypedTransform3D<f32, Src, Dst> = TypedTransform3D::create_scale(2.0, 3.0, 4.0)
+            .post_translate(vec3(10.0, 20.0, 30.0));
+        assert_eq!(apply(&t), (point2(12.0, 26.0), vec2(2.0, 6.0)));
+    }
+
+    #[test]
+    fn test_transform_scale() {
+        let s: TypedScale<f32, Src, Dst> = TypedScale::new(2.0);
+        assert_eq!(apply(&s), (point2(2.0, 4.0), vec2(2.0, 4.0)));
+    }
+
+    #[test]
+    fn test_transform_rotation() {
+        let r: TypedRotation2D<f32, Src, Dst> = TypedRotation2D::new(Angle::radians(FRAC_PI_2));
+        let (p, v) = apply(&r);
+        assert!(p.approx_eq(&point2(-2.0, 1.0)));
+        assert!(v.approx_eq(&vec2(-2.0, 1.0)));
+    }
+}
@@ -10,15 +10,18 @@
 use super::{UnknownUnit, Angle};
 use num::{One, Zero};
 use point::TypedPoint2D;
-use vector::{TypedVector2D, vec2};
+use vector::{TypedVector2D, Vector2D, vec2};
 use rect::TypedRect;
+use scale::TypedScale;
+use css::{self, ParseError};
 use transform3d::TypedTransform3D;
 use std::ops::{Add, Mul, Div, Sub, Neg};
 use std::marker::PhantomData;
 use approxeq::ApproxEq;
 use trig::Trig;
 use std::fmt;
-use num_traits::NumCast;
+use std::str::FromStr;
+use num_traits::{Float, NumCast};
 
 define_matrix! {
     /// A 2d transform stored as a 2 by 3 matrix in row-major order in memory.
@@ -93,6 +96,41 @@ impl<T: Copy, Src, Dst> TypedTransform2D<T, Src, Dst> {
         ]
     }
 
+    /// Returns the row at index `i` (between 0 and 2) of this transform.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> [T; 2] {
+        self.to_row_arrays()[i]
+    }
+
+    /// Returns the column at index `i` (between 0 and 1) of this transform.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn column(&self, i: usize) -> [T; 3] {
+        [
+            [self.m11, self.m21, self.m31],
+            [self.m12, self.m22, self.m32],
+        ][i]
+    }
+
+    /// Returns the image of the x axis by this transform, ignoring the translation
+    /// (the first row of the transform).
+    pub fn x_axis(&self) -> TypedVector2D<T, Dst> {
+        vec2(self.m11, self.m12)
+    }
+
+    /// Returns the image of the y axis by this transform, ignoring the translation
+    /// (the second row of the transform).
+    pub fn y_axis(&self) -> TypedVector2D<T, Dst> {
+        vec2(self.m21, self.m22)
+    }
+
+    /// Returns the translation part of this transform (the third row of the
+    /// transform).
+    pub fn translation(&self) -> TypedVector2D<T, Dst> {
+        vec2(self.m31, self.m32)
+    }
+
     /// Creates a transform from an array of 6 elements in row-major order.
     pub fn from_row_major_array(array: [T; 6]) -> Self {
         Self::row_major(
@@ -128,6 +166,22 @@ impl<T: Copy, Src, Dst> TypedTransform2D<T, Src, Dst> {
             p.m31, p.m32
         )
     }
+
+    /// Serializes this transform as a CSS `matrix(a, b, c, d, e, f)` function.
+    pub fn to_css_string(&self) -> String where T: fmt::Display {
+        css::to_function_string("matrix", &self.to_row_major_array())
+    }
+
+    /// Parses a CSS `matrix(a, b, c, d, e, f)` function.
+    ///
+    /// Only the `matrix()` function is supported, not other transform functions
+    /// nor lists of transform functions. Whitespace around the function and its
+    /// arguments is ignored, and the arguments may use scientific notation.
+    pub fn from_css_string(s: &str) -> Result<Self, ParseError> where T: Zero + FromStr {
+        let mut args = [T::zero(); 6];
+        try!(css::parse_function(s, "matrix", &mut args));
+        Ok(Self::from_row_major_array(args))
+    }
 }
 
 impl<T0: NumCast + Copy, Src, Dst> TypedTransform2D<T0, Src, Dst> {
@@ -248,6 +302,51 @@ where T: Copy + Clone +
         )
     }
 
+    /// Returns the transform that maps the `src` rectangle onto the `dst` rectangle,
+    /// with a translation and a possibly non-uniform scale.
+    ///
+    /// Returns `None` if `src` has a width or height of zero.
+    pub fn rect_to_rect(src: &TypedRect<T, Src>, dst: &TypedRect<T, Dst>) -> Option<Self> {
+        let zero = T::zero();
+        if src.size.width == zero || src.size.height == zero {
+            return None;
+        }
+
+        let sx = dst.size.width / src.size.width;
+        let sy = dst.size.height / src.size.height;
+        Some(TypedTransform2D::row_major(
+            sx, zero,
+            zero, sy,
+            dst.origin.x - src.origin.x * sx, dst.origin.y - src.origin.y * sy
+        ))
+    }
+
+    /// Returns a transform that flips the y axis within a space of the given height,
+    /// mapping `y` to `height - y`.
+    ///
+    /// This is useful to convert between y-down and y-up coordinate systems.
+    pub fn flip_y(height: T) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        TypedTransform2D::row_major(
+            one,  zero,
+            zero, zero - one,
+            zero, height
+        )
+    }
+
+    /// Returns a transform that flips the x axis within a space of the given width,
+    /// mapping `x` to `width - x`.
+    pub fn flip_x(width: T) -> Self {
+        let zero = T::zero();
+        let one = T::one();
+        TypedTransform2D::row_major(
+            zero - one, zero,
+            zero,       one,
+            width,      zero
+        )
+    }
+
     /// Returns a rotation transform.
     pub fn create_rotation(theta: Angle<T>) -> Self {
         let _0 = Zero::zero();
@@ -272,6 +371,74 @@ where T: Copy + Clone +
         self.pre_mul(&TypedTransform2D::create_rotation(theta))
     }
 
+    /// Returns a transform that applies self's transformation and then `mat`'s.
+    ///
+    /// This is `post_mul`, under a name that reads in the order the operations apply,
+    /// which is also the order in which the units chain: `then` and the `then_*`
+    /// methods can be chained from left to right like a sequence of steps.
+    ///
+    /// ```rust
+    /// use euclid::{TypedTransform2D, TypedScale, TypedPoint2D, vec2, Angle};
+    /// enum Local {}
+    /// enum Css {}
+    /// enum Device {}
+    ///
+    /// let dpr: TypedScale<f32, Css, Device> = TypedScale::new(2.0);
+    /// let t = TypedTransform2D::<f32, Local, Css>::create_translation(10.0, 0.0)
+    ///     .then_rotate(Angle::degrees(90.0))
+    ///     .then_scale(1.0, 3.0)
+    ///     .then_scale_by(dpr);
+    ///
+    /// // Applying the steps one after the other gives the same result.
+    /// let p: TypedPoint2D<f32, Local> = TypedPoint2D::new(1.0, 2.0);
+    /// let step = TypedPoint2D::<f32, Local>::new(p.x + 10.0, p.y);
+    /// // The rotation by 90 degrees maps (x, y) to (y, -x).
+    /// let step = TypedPoint2D::<f32, Css>::new(step.y, -step.x);
+    /// let step = TypedPoint2D::<f32, Css>::new(step.x, step.y * 3.0);
+    /// let expected: TypedPoint2D<f32, Device> = step * dpr;
+    /// assert!((t.transform_point(&p) - expected).length() < 1.0e-4);
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then<NewDst>(&self, mat: &TypedTransform2D<T, Dst, NewDst>) -> TypedTransform2D<T, Src, NewDst> {
+        self.post_mul(mat)
+    }
+
+    /// Returns a transform that applies self's transformation and then a translation.
+    ///
+    /// This is the same as `post_translate`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_translate(&self, v: TypedVector2D<T, Dst>) -> Self {
+        self.post_translate(v)
+    }
+
+    /// Returns a transform that applies self's transformation and then a rotation.
+    ///
+    /// This is the same as `post_rotate`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_rotate(&self, theta: Angle<T>) -> Self {
+        self.post_rotate(theta)
+    }
+
+    /// Returns a transform that applies self's transformation and then a scale.
+    ///
+    /// This is the same as `post_scale`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_scale(&self, x: T, y: T) -> Self {
+        self.post_scale(x, y)
+    }
+
+    /// Returns a transform that applies self's transformation and then converts the
+    /// result into another unit with the given scale factor.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_scale_by<NewDst>(&self, scale: TypedScale<T, Dst, NewDst>) -> TypedTransform2D<T, Src, NewDst> {
+        self.then(&TypedTransform2D::create_scale(scale.get(), scale.get()))
+    }
+
     /// Returns the given point transformed by this transform.
     #[inline]
     #[cfg_attr(feature = "unstable", must_use)]
@@ -281,6 +448,9 @@ where T: Copy + Clone +
     }
 
     /// Returns the given vector transformed by this matrix.
+    ///
+    /// Only the linear part of the transform applies: vectors represent directions
+    /// and displacements, so unlike points they are not affected by translations.
     #[inline]
     #[cfg_attr(feature = "unstable", must_use)]
     pub fn transform_vector(&self, vec: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
@@ -306,6 +476,15 @@ where T: Copy + Clone +
         self.m11 * self.m22 - self.m12 * self.m21
     }
 
+    /// Returns true if this transform includes a reflection, that is if the sign
+    /// of its determinant is negative.
+    ///
+    /// This can be used to flip the winding order used for back-face culling.
+    #[inline]
+    pub fn flips_handedness(&self) -> bool {
+        self.determinant() < Zero::zero()
+    }
+
     /// Returns the inverse transform if possible.
     #[cfg_attr(feature = "unstable", must_use)]
     pub fn inverse(&self) -> Option<TypedTransform2D<T, Dst, Src>> {
@@ -348,6 +527,33 @@ where T: Copy + Clone +
             self.m31, self.m32,
         )
     }   
+
+    /// Returns a transform that applies this transform followed by `scale`,
+    /// converting its destination to a new unit.
+    ///
+    /// This is typically used to go from a transform in CSS pixels to a
+    /// transform in device pixels.
+    #[inline]
+    pub fn with_destination_scale<NewDst>(&self, scale: TypedScale<T, Dst, NewDst>) -> TypedTransform2D<T, Src, NewDst> {
+        let s = scale.get();
+        TypedTransform2D::row_major(
+            self.m11 * s, self.m12 * s,
+            self.m21 * s, self.m22 * s,
+            self.m31 * s, self.m32 * s,
+        )
+    }
+
+    /// Returns a transform that applies `scale` followed by this transform,
+    /// converting its source to a new unit.
+    #[inline]
+    pub fn with_source_scale<NewSrc>(&self, scale: TypedScale<T, NewSrc, Src>) -> TypedTransform2D<T, NewSrc, Dst> {
+        let s = scale.get();
+        TypedTransform2D::row_major(
+            self.m11 * s, self.m12 * s,
+            self.m21 * s, self.m22 * s,
+            self.m31,     self.m32,
+        )
+    }
 }
 
 impl <T, Src, Dst> TypedTransform2D<T, Src, Dst>
@@ -367,6 +573,99 @@ where T: Copy + Clone +
 
 }
 
+/// Composes a 2d transform with a 3d transform that applies after it.
+///
+/// The 2d transform is first lifted with `to_3d`, which leaves the z axis unchanged,
+/// and then multiplied with `post_mul`: `a * b` transforms points with `a` and then
+/// with `b`, which is why the units chain from `Src` through `Mid` to `Dst`.
+impl<T, Src, Mid, Dst> Mul<TypedTransform3D<T, Mid, Dst>> for TypedTransform2D<T, Src, Mid>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Sub<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Neg<Output=T> +
+         PartialOrd +
+         Trig +
+         One + Zero {
+    type Output = TypedTransform3D<T, Src, Dst>;
+    #[inline]
+    fn mul(self, other: TypedTransform3D<T, Mid, Dst>) -> TypedTransform3D<T, Src, Dst> {
+        self.to_3d().post_mul(&other)
+    }
+}
+
+/// Composes a 3d transform with a 2d transform that applies after it.
+///
+/// See the `Mul<TypedTransform3D>` implementation for `TypedTransform2D`.
+impl<T, Src, Mid, Dst> Mul<TypedTransform2D<T, Mid, Dst>> for TypedTransform3D<T, Src, Mid>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Sub<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Neg<Output=T> +
+         PartialOrd +
+         Trig +
+         One + Zero {
+    type Output = TypedTransform3D<T, Src, Dst>;
+    #[inline]
+    fn mul(self, other: TypedTransform2D<T, Mid, Dst>) -> TypedTransform3D<T, Src, Dst> {
+        self.post_mul(&other.to_3d())
+    }
+}
+
+impl<T: Float + Trig, Src, Dst> TypedTransform2D<T, Src, Dst> {
+    /// Returns the scale factors along the x and y axes, which are the lengths of
+    /// the transformed x and y basis vectors.
+    ///
+    /// If the transform includes a reflection (its determinant is negative), the
+    /// y scale factor is negative, so that `create_scale(sx, sy).post_rotate(r)`
+    /// reproduces the linear part of transforms without skew, with `(sx, sy)` from
+    /// `get_scale()` and `r` from `get_rotation()`.
+    pub fn get_scale(&self) -> Vector2D<T> {
+        let sx = self.m11.hypot(self.m12);
+        let sy = self.m21.hypot(self.m22);
+        if self.determinant() < T::zero() {
+            vec2(sx, -sy)
+        } else {
+            vec2(sx, sy)
+        }
+    }
+
+    /// Returns the rotation of the transformed x basis vector, with the same
+    /// orientation as `create_rotation`.
+    ///
+    /// Translation and scale, including reflections along the y axis, don't affect
+    /// the result. A reflection along the x axis shows up as a rotation of PI.
+    pub fn get_rotation(&self) -> Angle<T> {
+        Angle::radians((-self.m12).atan2(self.m11))
+    }
+
+    /// Returns this transform with its scale factors along the x and y axes (see
+    /// `get_scale`) clamped between `min` and `max`, keeping its rotation and
+    /// translation.
+    ///
+    /// This is done by rescaling the transformed x and y basis vectors, so reflections
+    /// are preserved as well. `min` and `max` apply to the absolute value of the scale
+    /// factors. If a scale factor is zero or not finite, the scale can't be decomposed
+    /// and this returns the transform unchanged.
+    pub fn with_clamped_scale(&self, min: T, max: T) -> Self {
+        let sx = self.m11.hypot(self.m12);
+        let sy = self.m21.hypot(self.m22);
+        if sx == T::zero() || sy == T::zero() || !sx.is_finite() || !sy.is_finite() {
+            return *self;
+        }
+        let fx = sx.max(min).min(max) / sx;
+        let fy = sy.max(min).min(max) / sy;
+        TypedTransform2D::row_major(
+            self.m11 * fx, self.m12 * fx,
+            self.m21 * fy, self.m22 * fy,
+            self.m31, self.m32,
+        )
+    }
+}
+
 impl<T: ApproxEq<T>, Src, Dst> TypedTransform2D<T, Src, Dst> {
     pub fn approx_eq(&self, other: &Self) -> bool {
         self.m11.approx_eq(&other.m11) && self.m12.approx_eq(&other.m12) &&
@@ -379,8 +678,25 @@ impl<T: Copy + fmt::Debug, Src, Dst> fmt::Debug for TypedTransform2D<T, Src, Dst
 where T: Copy + fmt::Debug +
          PartialEq +
          One + Zero {
+    /// The alternate form (`{:#?}`) prints the matrix as a 3 by 2 grid with one row per
+    /// line and each column right-aligned.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_identity() {
+        if f.alternate() {
+            let cells: Vec<String> = self.to_row_major_array().iter()
+                .map(|cell| format!("{:?}", cell))
+                .collect();
+            let mut widths = [0, 0];
+            for (i, cell) in cells.iter().enumerate() {
+                widths[i % 2] = widths[i % 2].max(cell.chars().count());
+            }
+            for (i, row) in cells.chunks(2).enumerate() {
+                if i > 0 {
+                    try!(writeln!(f));
+                }
+                try!(write!(f, "[{:>w0$}, {:>w1$}]", row[0], row[1], w0 = widths[0], w1 = widths[1]));
+            }
+            Ok(())
+        } else if self.is_identity() {
             write!(f, "[I]")
         } else {
             self.to_row_major_array().fmt(f)
@@ -395,7 +711,7 @@ mod test {
     use point::Point2D;
     use Angle;
 
-    use std::f32::consts::FRAC_PI_2;
+    use std::f32::consts::{FRAC_PI_2, PI};
 
     type Mat = Transform2D<f32>;
 
@@ -500,6 +816,87 @@ mod test {
         assert!(t.pre_mul(&r).transform_point(&a).approx_eq(&t.transform_point(&r.transform_point(&a))));
     }
 
+    #[test]
+    pub fn test_with_scale() {
+        struct Css;
+        struct Layout;
+        struct Device;
+
+        let m: TypedTransform2D<f32, Layout, Css> = TypedTransform2D::create_translation(10.0, 20.0);
+        let layout_to_css: TypedScale<f32, Layout, Css> = TypedScale::new(3.0);
+        let css_to_device: TypedScale<f32, Css, Device> = TypedScale::new(2.0);
+        let p = TypedPoint2D::new(1.0, 2.0);
+
+        let to_device: TypedTransform2D<f32, Layout, Device> = m.with_destination_scale(css_to_device);
+        assert_eq!(to_device.transform_point(&p), TypedPoint2D::new(22.0, 44.0));
+        assert_eq!(to_device.transform_point(&p), css_to_device.transform_point(&m.transform_point(&p)));
+
+        let m: TypedTransform2D<f32, Css, Device> = TypedTransform2D::create_translation(10.0, 20.0);
+        let from_layout: TypedTransform2D<f32, Layout, Device> = m.with_source_scale(layout_to_css);
+        assert_eq!(from_layout.transform_point(&p), TypedPoint2D::new(13.0, 26.0));
+        assert_eq!(from_layout.transform_point(&p), m.transform_point(&layout_to_css.transform_point(&p)));
+    }
+
+    #[test]
+    pub fn test_css_matrix() {
+        let m = Mat::row_major(1.0, 2.5, -3.0, 4.0, 5.0, 60.0);
+        assert_eq!(m.to_css_string(), "matrix(1, 2.5, -3, 4, 5, 60)");
+        assert_eq!(Mat::from_css_string(&m.to_css_string()), Ok(m));
+        assert_eq!(Mat::from_css_string("  matrix( 1,2.5 ,-3e0, 0.4E1,\t5, 6e1 ) "), Ok(m));
+
+        assert_eq!(Mat::from_css_string("matrix(1, 0, 0, 1, 0)"), Err(ParseError::InvalidArgumentCount));
+        assert_eq!(Mat::from_css_string("scale(1, 0, 0, 1, 0, 0)"), Err(ParseError::InvalidFunction));
+        assert_eq!(Mat::from_css_string("matrix(1, 0, 0, 1, 0px, 0)"), Err(ParseError::InvalidNumber));
+    }
+
+    #[test]
+    pub fn test_rows_columns_and_axes() {
+        let m = Mat::create_scale(2.0, 3.0).post_translate(vec2(5.0, 6.0));
+
+        assert_eq!(m.x_axis(), vec2(2.0, 0.0));
+        assert_eq!(m.y_axis(), vec2(0.0, 3.0));
+        assert_eq!(m.translation(), vec2(5.0, 6.0));
+
+        assert_eq!(m.row(0), [2.0, 0.0]);
+        assert_eq!(m.row(2), [5.0, 6.0]);
+        assert_eq!(m.column(0), [2.0, 0.0, 5.0]);
+        assert_eq!(m.column(1), [0.0, 3.0, 6.0]);
+    }
+
+    #[test]
+    pub fn test_rect_to_rect() {
+        use rect::rect;
+
+        let src = rect(10.0, 20.0, 100.0, 50.0);
+        let dst = rect(-5.0, 0.0, 20.0, 200.0);
+        let m = Mat::rect_to_rect(&src, &dst).unwrap();
+
+        assert!(m.transform_point(&src.origin).approx_eq(&dst.origin));
+        assert!(m.transform_point(&src.top_right()).approx_eq(&dst.top_right()));
+        assert!(m.transform_point(&src.bottom_left()).approx_eq(&dst.bottom_left()));
+        assert!(m.transform_point(&src.bottom_right()).approx_eq(&dst.bottom_right()));
+        assert!(m.transform_rect(&src).origin.approx_eq(&dst.origin));
+
+        assert!(Mat::rect_to_rect(&rect(0.0, 0.0, 0.0, 10.0), &dst).is_none());
+    }
+
+    #[test]
+    pub fn test_flip() {
+        let p = Point2D::new(20.0, 10.0);
+        assert_eq!(Mat::flip_y(100.0).transform_point(&p), Point2D::new(20.0, 90.0));
+        assert_eq!(Mat::flip_x(50.0).transform_point(&p), Point2D::new(30.0, 10.0));
+        assert!(Mat::flip_y(100.0).post_mul(&Mat::flip_y(100.0)).is_identity());
+    }
+
+    #[test]
+    pub fn test_flips_handedness() {
+        assert!(Mat::create_scale(-1.0, 1.0).flips_handedness());
+        assert!(Mat::create_scale(1.0, -2.0).post_translate(vec2(3.0, 0.0)).flips_handedness());
+        assert!(!Mat::create_scale(-1.0, -1.0).flips_handedness());
+        assert!(!Mat::create_rotation(rad(FRAC_PI_2)).flips_handedness());
+        assert!(!Mat::identity().flips_handedness());
+    }
+
     #[test]
     fn test_size_of() {
         use std::mem::size_of;
@@ -513,6 +910,23 @@ mod test {
         assert!(m1.is_identity());
         let m2 = m1.post_translate(vec2(0.1, 0.0));
         assert!(!m2.is_identity());
+
+        let m = Mat::create_rotation(rad(FRAC_PI_2)).post_translate(vec2(1.0, 2.0));
+        assert_eq!(m.post_mul(&Mat::identity()), m);
+        assert_eq!(m.pre_mul(&Mat::identity()), m);
+    }
+
+    #[test]
+    pub fn test_debug() {
+        let m = Mat::row_major(1.0, 2.5, -10.0, 4.0, 5.0, 60.0);
+        assert_eq!(format!("{:?}", Mat::identity()), "[I]");
+        assert_eq!(format!("{:?}", m), "[1.0, 2.5, -10.0, 4.0, 5.0, 60.0]");
+        assert_eq!(
+            format!("{:#?}", m),
+            "[  1.0,  2.5]\n\
+             [-10.0,  4.0]\n\
+             [  5.0, 60.0]"
+        );
     }
 
     #[test]
@@ -521,5 +935,83 @@ mod test {
         let m1 = Mat::create_translation(1.0, 1.0);
         let v1 = vec2(10.0, -10.0);
         assert_eq!(v1, m1.transform_vector(&v1));
+
+        // Only the linear part applies, so the result is the difference of the
+        // transformed end points of the vector.
+        let m2 = Mat::create_scale(2.0, 3.0).post_rotate(rad(FRAC_PI_2)).post_translate(vec2(5.0, 6.0));
+        let p = Point2D::new(1.0, 2.0);
+        let v = m2.transform_vector(&v1);
+        assert!(v.approx_eq(&Mat::create_scale(2.0, 3.0).post_rotate(rad(FRAC_PI_2)).transform_vector(&v1)));
+        let delta = m2.transform_point(&(p + v1)) - m2.transform_point(&p);
+        assert!(v.approx_eq_eps(&delta, &vec2(1.0e-4, 1.0e-4)));
+        assert!(!m2.transform_point(&v1.to_point()).to_vector().approx_eq(&v));
+    }
+
+    #[test]
+    pub fn test_get_scale_and_rotation() {
+        let m = Mat::create_scale(2.0, 3.0).post_rotate(rad(0.5)).post_translate(vec2(5.0, 6.0));
+        assert!(m.get_scale().approx_eq(&vec2(2.0, 3.0)));
+        assert!(m.get_rotation().get().approx_eq(&0.5));
+
+        assert!(Mat::create_rotation(rad(-2.0)).get_rotation().get().approx_eq(&-2.0));
+        assert!(Mat::identity().get_scale().approx_eq(&vec2(1.0, 1.0)));
+
+        // Reflections put the sign on the y scale.
+        let m = Mat::create_scale(2.0, -3.0).post_rotate(rad(0.5));
+        assert!(m.get_scale().approx_eq(&vec2(2.0, -3.0)));
+        assert!(m.get_rotation().get().approx_eq(&0.5));
+        let s = m.get_scale();
+        assert!(Mat::create_scale(s.x, s.y).post_rotate(m.get_rotation()).approx_eq(&m));
+
+        let m = Mat::create_scale(-2.0, 3.0);
+        assert!(m.get_scale().approx_eq(&vec2(2.0, -3.0)));
+        assert!(m.get_rotation().get().abs().approx_eq(&PI));
+    }
+
+    #[test]
+    pub fn test_css_string_round_trip() {
+        let m = Mat::create_rotation(rad(0.5)).post_translate(vec2(10.0, -20.5));
+        assert_eq!(Mat::from_css_string(&m.to_css_string()), Ok(m));
+        assert_eq!(Mat::from_css_string("matrix3d(1, 0, 0, 1, 0, 0)"), Err(ParseError::InvalidFunction));
+        assert_eq!(Mat::from_css_string("matrix(1, 0, 0, 1, 0, 0"), Err(ParseError::InvalidFunction));
+    }
+
+    #[test]
+    pub fn test_with_clamped_scale() {
+        let m = Mat::create_scale(1000.0, 0.5).post_rotate(rad(0.5)).post_translate(vec2(5.0, 6.0));
+        let clamped = m.with_clamped_scale(1.0, 10.0);
+        assert!(clamped.get_scale().approx_eq(&vec2(10.0, 1.0)));
+        assert!(clamped.get_rotation().get().approx_eq(&0.5));
+        assert_eq!(clamped.translation(), m.translation());
+        assert!(clamped.approx_eq(&Mat::create_scale(10.0, 1.0).post_rotate(rad(0.5)).post_translate(vec2(5.0, 6.0))));
+
+        // Reflections are kept.
+        let m = Mat::create_scale(100.0, -100.0);
+        assert!(m.with_clamped_scale(0.1, 2.0).approx_eq(&Mat::create_scale(2.0, -2.0)));
+
+        // Scales in range are unchanged.
+        let m = Mat::create_scale(2.0, 3.0).post_rotate(rad(1.0));
+        assert!(m.with_clamped_scale(0.5, 4.0).approx_eq(&m));
+
+        // Degenerate transforms can't be decomposed.
+        let m = Mat::create_scale(0.0, 1000.0);
+        assert_eq!(m.with_clamped_scale(1.0, 10.0), m);
+    }
+
+    #[test]
+    pub fn test_mul_3d() {
+        use transform3d::Transform3D;
+
+        let m2 = Mat::create_rotation(rad(FRAC_PI_2)).post_translate(vec2(1.0, 2.0));
+        let m3 = Transform3D::create_scale(2.0, 3.0, 4.0)
+            .post_rotate(1.0, 0.0, 0.0, rad(0.5));
+
+        assert_eq!(m2 * m3, m2.to_3d().post_mul(&m3));
+        assert_eq!(m3 * m2, m3.post_mul(&m2.to_3d()));
+
+        // The 2d transform applies first.
+        let p = Point2D::new(1.0, 1.0);
+        let expected = m3.transform_point2d(&m2.transform_point(&p));
+        assert!((m2 * m3).transform_point2d(&p).approx_eq(&expected));
     }
 }
@@ -13,13 +13,16 @@ use trig::Trig;
 use point::{TypedPoint2D, TypedPoint3D, point2, point3};
 use vector::{TypedVector2D, TypedVector3D, vec2, vec3};
 use rect::TypedRect;
+use plane::Plane;
 use transform2d::TypedTransform2D;
 use scale::TypedScale;
+use css::{self, ParseError};
 use num::{One, Zero};
 use std::ops::{Add, Mul, Sub, Div, Neg};
 use std::marker::PhantomData;
 use std::fmt;
-use num_traits::NumCast;
+use std::str::FromStr;
+use num_traits::{Float, NumCast};
 
 define_matrix! {
     /// A 3d transform stored as a 4 by 4 matrix in row-major order in memory.
@@ -49,6 +52,22 @@ impl<T, Src, Dst> TypedTransform3D<T, Src, Dst> {
     ///
     /// For example, the translation terms m41, m42, m43 on the last row with the
     /// row-major convention) are the 13rd, 14th and 15th parameters.
+    ///
+    /// This is also the order of the 16 arguments of the CSS `matrix3d()` function
+    /// (see <https://drafts.csswg.org/css-transforms-2/#funcdef-matrix3d>):
+    ///
+    /// ```rust
+    /// use euclid::{Transform3D, point3};
+    /// // matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 10, 20, 30, 1)
+    /// let m = Transform3D::row_major(
+    ///     1.0, 0.0, 0.0, 0.0,
+    ///     0.0, 1.0, 0.0, 0.0,
+    ///     0.0, 0.0, 1.0, 0.0,
+    ///     10.0, 20.0, 30.0, 1.0,
+    /// );
+    /// assert_eq!((m.m41, m.m42, m.m43), (10.0, 20.0, 30.0));
+    /// assert_eq!(m.transform_point3d(&point3(1.0, 2.0, 3.0)), point3(11.0, 22.0, 33.0));
+    /// ```
     #[inline]
     pub fn row_major(
             m11: T, m12: T, m13: T, m14: T,
@@ -177,6 +196,33 @@ where T: Copy + Clone +
         )
     }
 
+    /// Returns true if this transform can be represented with a TypedTransform2D, up
+    /// to the given tolerance on each of the terms that must be zero or one.
+    ///
+    /// Unlike `is_2d`, this accepts the small residues that accumulate when composing
+    /// transforms, for example when a rotation around the x axis is undone by another
+    /// one.
+    pub fn is_2d_eps(&self, eps: &T) -> bool where T: ApproxEq<T> {
+        let (zero, one): (T, T) = (Zero::zero(), One::one());
+        self.m31.approx_eq_eps(&zero, eps) && self.m32.approx_eq_eps(&zero, eps) &&
+        self.m13.approx_eq_eps(&zero, eps) && self.m23.approx_eq_eps(&zero, eps) &&
+        self.m43.approx_eq_eps(&zero, eps) && self.m14.approx_eq_eps(&zero, eps) &&
+        self.m24.approx_eq_eps(&zero, eps) && self.m34.approx_eq_eps(&zero, eps) &&
+        self.m33.approx_eq_eps(&one, eps) && self.m44.approx_eq_eps(&one, eps)
+    }
+
+    /// Returns the equivalent 2d transform if this transform has no effect on the z
+    /// axis and no perspective, within the `ApproxEq` epsilon of `T`, or `None`
+    /// otherwise.
+    ///
+    /// This is the checked version of `to_2d`, see `is_2d_eps`.
+    pub fn try_to_2d(&self) -> Option<TypedTransform2D<T, Src, Dst>> where T: ApproxEq<T> {
+        if !self.is_2d_eps(&T::approx_epsilon()) {
+            return None;
+        }
+        Some(self.to_2d())
+    }
+
     /// Check whether shapes on the XY plane with Z pointing towards the
     /// screen transformed by this matrix would be facing back.
     pub fn is_backface_visible(&self) -> bool {
@@ -354,6 +400,25 @@ where T: Copy + Clone +
         Some(m.mul_s(_1 / det))
     }
 
+    /// Maps a point from the destination space of this projection, for example
+    /// normalized device coordinates, back to the source space.
+    ///
+    /// This relies on `inverse`, which handles the `ortho` and perspective projections
+    /// like any other invertible transform. To get the ray going through a point of the
+    /// screen for picking, unproject it at the near and far depths (`-1` and `1` in
+    /// normalized device coordinates) and take the line joining both results.
+    ///
+    /// Returns `None` if the transform is not invertible or if the point maps to
+    /// infinity.
+    pub fn unproject(&self, p: &TypedPoint3D<T, Dst>) -> Option<TypedPoint3D<T, Src>> {
+        let m = self.inverse()?;
+        let w = p.x * m.m14 + p.y * m.m24 + p.z * m.m34 + m.m44;
+        if w == Zero::zero() {
+            return None;
+        }
+        Some(m.transform_point3d(p))
+    }
+
     /// Compute the determinant of the transform.
     pub fn determinant(&self) -> T {
         self.m14 * self.m23 * self.m32 * self.m41 -
@@ -382,6 +447,35 @@ where T: Copy + Clone +
         self.m11 * self.m22 * self.m33 * self.m44
     }
 
+    /// Returns true if this transform includes a reflection, that is if the sign
+    /// of the determinant of its upper-left 3x3 part is negative.
+    ///
+    /// This can be used to flip the winding order used for back-face culling.
+    /// The projective part of the transform is ignored.
+    pub fn flips_handedness(&self) -> bool {
+        let det3 = self.m11 * (self.m22 * self.m33 - self.m23 * self.m32)
+            - self.m12 * (self.m21 * self.m33 - self.m23 * self.m31)
+            + self.m13 * (self.m21 * self.m32 - self.m22 * self.m31);
+        det3 < Zero::zero()
+    }
+
+    /// Returns the equivalent of this transform in a coordinate system of the opposite
+    /// handedness, for example to use a transform authored in a left-handed system in
+    /// a right-handed one.
+    ///
+    /// The conversion negates the z axis: the result is this transform conjugated by
+    /// `create_scale(1, 1, -1)`, so that transforming a point with its z coordinate
+    /// negated (see `TypedPoint3D::flip_handedness`) gives this transform's result with
+    /// its z coordinate negated. Converting twice gives back the original transform.
+    pub fn convert_handedness(&self) -> Self {
+        TypedTransform3D::row_major(
+            self.m11, self.m12, -self.m13, self.m14,
+            self.m21, self.m22, -self.m23, self.m24,
+            -self.m31, -self.m32, self.m33, -self.m34,
+            self.m41, self.m42, -self.m43, self.m44,
+        )
+    }
+
     /// Multiplies all of the transform's component by a scalar and returns the result.
     #[cfg_attr(feature = "unstable", must_use)]
     pub fn mul_s(&self, x: T) -> Self {
@@ -449,13 +543,30 @@ where T: Copy + Clone +
 
     /// Returns a rectangle that encompasses the result of transforming the given rectangle by this
     /// transform.
-    pub fn transform_rect(&self, rect: &TypedRect<T, Src>) -> TypedRect<T, Dst> {
-        TypedRect::from_points(&[
-            self.transform_point2d(&rect.origin),
-            self.transform_point2d(&rect.top_right()),
-            self.transform_point2d(&rect.bottom_left()),
-            self.transform_point2d(&rect.bottom_right()),
-        ])
+    ///
+    /// The corners of the rectangle are projected with a perspective divide. Returns `None`
+    /// if the homogeneous `w` coordinate of any of them is negative or zero, which means
+    /// that the transformed rectangle is not entirely in front of the camera plane and
+    /// its projection is not bounded.
+    pub fn transform_rect(&self, rect: &TypedRect<T, Src>) -> Option<TypedRect<T, Dst>> {
+        Some(TypedRect::from_points(&[
+            self.transform_point2d_in_front(&rect.origin)?,
+            self.transform_point2d_in_front(&rect.top_right())?,
+            self.transform_point2d_in_front(&rect.bottom_left())?,
+            self.transform_point2d_in_front(&rect.bottom_right())?,
+        ]))
+    }
+
+    /// Like `transform_point2d`, but returns `None` if the point ends up behind or on
+    /// the camera plane (its `w` coordinate is not positive).
+    fn transform_point2d_in_front(&self, p: &TypedPoint2D<T, Src>) -> Option<TypedPoint2D<T, Dst>> {
+        let w = p.x * self.m14 + p.y * self.m24 + self.m44;
+        if w <= Zero::zero() {
+            return None;
+        }
+        let x = p.x * self.m11 + p.y * self.m21 + self.m41;
+        let y = p.x * self.m12 + p.y * self.m22 + self.m42;
+        Some(point2(x / w, y / w))
     }
 
     /// Create a 3d translation transform
@@ -558,7 +669,53 @@ where T: Copy + Clone +
         self.pre_mul(&TypedTransform3D::create_rotation(x, y, z, theta))
     }
 
-    /// Create a 2d skew transform.
+    /// Returns a transform that applies self's transformation and then `mat`'s.
+    ///
+    /// This is `post_mul`, under a name that reads in the order the operations apply.
+    /// See `TypedTransform2D::then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then<NewDst>(&self, mat: &TypedTransform3D<T, Dst, NewDst>) -> TypedTransform3D<T, Src, NewDst> {
+        self.post_mul(mat)
+    }
+
+    /// Returns a transform that applies self's transformation and then a translation.
+    ///
+    /// This is the same as `post_translate`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_translate(&self, v: TypedVector3D<T, Dst>) -> Self {
+        self.post_translate(v)
+    }
+
+    /// Returns a transform that applies self's transformation and then a rotation.
+    ///
+    /// This is the same as `post_rotate`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_rotate(&self, x: T, y: T, z: T, theta: Angle<T>) -> Self {
+        self.post_rotate(x, y, z, theta)
+    }
+
+    /// Returns a transform that applies self's transformation and then a scale.
+    ///
+    /// This is the same as `post_scale`, see `then`.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_scale(&self, x: T, y: T, z: T) -> Self {
+        self.post_scale(x, y, z)
+    }
+
+    /// Returns a transform that applies self's transformation and then converts the
+    /// result into another unit with the given scale factor.
+    #[inline]
+    #[cfg_attr(feature = "unstable", must_use)]
+    pub fn then_scale_by<NewDst>(&self, scale: TypedScale<T, Dst, NewDst>) -> TypedTransform3D<T, Src, NewDst> {
+        self.then(&TypedTransform3D::from_scale(scale))
+    }
+
+    /// Create a 2d skew transform, skewing by `alpha` along the x axis and by `beta`
+    /// along the y axis, like the CSS `skew(alpha, beta)` function.
     ///
     /// See <https://drafts.csswg.org/css-transforms/#funcdef-skew>
     pub fn create_skew(alpha: Angle<T>, beta: Angle<T>) -> Self {
@@ -632,6 +789,44 @@ impl<T: Copy, Src, Dst> TypedTransform3D<T, Src, Dst> {
         ]
     }
 
+    /// Returns the row at index `i` (between 0 and 3) of this transform.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> [T; 4] {
+        self.to_row_arrays()[i]
+    }
+
+    /// Returns the column at index `i` (between 0 and 3) of this transform.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn column(&self, i: usize) -> [T; 4] {
+        self.to_column_arrays()[i]
+    }
+
+    /// Returns the image of the x axis by this transform, ignoring the translation
+    /// and projective parts (the first row of the transform).
+    pub fn x_axis(&self) -> TypedVector3D<T, Dst> {
+        vec3(self.m11, self.m12, self.m13)
+    }
+
+    /// Returns the image of the y axis by this transform, ignoring the translation
+    /// and projective parts (the second row of the transform).
+    pub fn y_axis(&self) -> TypedVector3D<T, Dst> {
+        vec3(self.m21, self.m22, self.m23)
+    }
+
+    /// Returns the image of the z axis by this transform, ignoring the translation
+    /// and projective parts (the third row of the transform).
+    pub fn z_axis(&self) -> TypedVector3D<T, Dst> {
+        vec3(self.m31, self.m32, self.m33)
+    }
+
+    /// Returns the translation part of this transform (the fourth row of the
+    /// transform, without its projective part).
+    pub fn translation(&self) -> TypedVector3D<T, Dst> {
+        vec3(self.m41, self.m42, self.m43)
+    }
+
     /// Creates a transform from an array of 16 elements in row-major order.
     pub fn from_array(array: [T; 16]) -> Self {
         Self::row_major(
@@ -651,6 +846,93 @@ impl<T: Copy, Src, Dst> TypedTransform3D<T, Src, Dst> {
             array[3][0], array[3][1], array[3][2], array[3][3],
         )
     }
+
+    /// Serializes this transform as a CSS `matrix3d()` function, whose 16 arguments
+    /// are the components of the transform in row-major order.
+    pub fn to_css_string(&self) -> String where T: fmt::Display {
+        css::to_function_string("matrix3d", &self.to_row_major_array())
+    }
+
+    /// Parses a CSS `matrix3d()` function.
+    ///
+    /// Only the `matrix3d()` function is supported, not other transform functions
+    /// nor lists of transform functions. Whitespace around the function and its
+    /// arguments is ignored, and the arguments may use scientific notation. See
+    /// `TypedTransform2D::from_css_string` for the 2d `matrix()` function.
+    pub fn from_css_string(s: &str) -> Result<Self, ParseError> where T: Zero + FromStr {
+        let mut args = [T::zero(); 16];
+        try!(css::parse_function(s, "matrix3d", &mut args));
+        Ok(Self::from_array(args))
+    }
+}
+
+impl<T: Float + ApproxEq<T>, Src, Dst> TypedTransform3D<T, Src, Dst> {
+    /// Returns the given surface normal transformed by this transform.
+    ///
+    /// Normals are transformed by the inverse-transpose of the upper-left 3x3 part of the
+    /// matrix rather than by the matrix itself, so that they stay perpendicular to the
+    /// transformed surface under non-uniform scales and skews. The result is normalized.
+    ///
+    /// Returns `None` if the 3x3 part of the matrix is not invertible.
+    pub fn transform_normal(&self, n: TypedVector3D<T, Src>) -> Option<TypedVector3D<T, Dst>> {
+        // Cofactors of the 3x3 part; the inverse-transpose is the cofactor matrix
+        // divided by the determinant.
+        let c11 = self.m22 * self.m33 - self.m23 * self.m32;
+        let c12 = self.m23 * self.m31 - self.m21 * self.m33;
+        let c13 = self.m21 * self.m32 - self.m22 * self.m31;
+        let c21 = self.m13 * self.m32 - self.m12 * self.m33;
+        let c22 = self.m11 * self.m33 - self.m13 * self.m31;
+        let c23 = self.m12 * self.m31 - self.m11 * self.m32;
+        let c31 = self.m12 * self.m23 - self.m13 * self.m22;
+        let c32 = self.m13 * self.m21 - self.m11 * self.m23;
+        let c33 = self.m11 * self.m22 - self.m12 * self.m21;
+
+        let det = self.m11 * c11 + self.m12 * c12 + self.m13 * c13;
+        if det == T::zero() {
+            return None;
+        }
+
+        let v: TypedVector3D<T, Dst> = vec3(
+            (n.x * c11 + n.y * c21 + n.z * c31) / det,
+            (n.x * c12 + n.y * c22 + n.z * c32) / det,
+            (n.x * c13 + n.y * c23 + n.z * c33) / det,
+        );
+        Some(v.normalize())
+    }
+
+    /// Returns the planes of the view frustum of this projection transform, in the
+    /// order left, right, bottom, top, near, far.
+    ///
+    /// The planes are extracted from the matrix with the Gribb-Hartmann method and are
+    /// expressed in the source space, with normalized normals pointing towards the
+    /// inside of the frustum. Points that have a positive signed distance to all six
+    /// planes are the ones that land in the `-w <= x, y, z <= w` clip volume after
+    /// transformation.
+    pub fn frustum_planes(&self) -> [Plane<T, Src>; 6] {
+        // With the row-vector convention the clip coordinates are the dot products
+        // of the homogeneous point with the columns of the matrix.
+        let c1 = (self.m11, self.m21, self.m31, self.m41);
+        let c2 = (self.m12, self.m22, self.m32, self.m42);
+        let c3 = (self.m13, self.m23, self.m33, self.m43);
+        let c4 = (self.m14, self.m24, self.m34, self.m44);
+
+        let plane = |a: (T, T, T, T), b: (T, T, T, T), sign: T| {
+            Plane::new(
+                vec3(a.0 + sign * b.0, a.1 + sign * b.1, a.2 + sign * b.2),
+                a.3 + sign * b.3,
+            ).normalize()
+        };
+        let (one, minus_one) = (T::one(), -T::one());
+
+        [
+            plane(c4, c1, one),
+            plane(c4, c1, minus_one),
+            plane(c4, c2, one),
+            plane(c4, c2, minus_one),
+            plane(c4, c3, one),
+            plane(c4, c3, minus_one),
+        ]
+    }
 }
 
 impl<T0: NumCast + Copy, Src, Dst> TypedTransform3D<T0, Src, Dst> {
@@ -696,10 +978,11 @@ mod tests {
     use approxeq::ApproxEq;
     use transform2d::Transform2D;
     use point::{Point2D, Point3D};
+    use rect::rect;
     use Angle;
     use super::*;
 
-    use std::f32::consts::{FRAC_PI_2, PI};
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
     type Mf32 = Transform3D<f32>;
 
@@ -779,6 +1062,25 @@ mod tests {
         assert!(!Mf32::create_rotation(0.0, 1.0, 0.0, rad(0.7854)).is_2d());
     }
 
+    #[test]
+    pub fn test_is_2d_eps() {
+        let r = Mf32::create_rotation(1.0, 0.0, 0.0, rad(0.3))
+            .post_translate(vec3(1.0, 0.0, 0.0))
+            .post_rotate(1.0, 0.0, 0.0, rad(-0.3));
+        assert!(!r.is_2d());
+        assert!(r.is_2d_eps(&1.0e-6));
+        assert!(r.try_to_2d().unwrap().approx_eq(&Transform2D::create_translation(1.0, 0.0)));
+
+        let t = Mf32::create_translation(1.0, 2.0, 0.0);
+        assert_eq!(t.try_to_2d(), Some(Transform2D::create_translation(1.0, 2.0)));
+
+        assert!(!Mf32::create_rotation(0.0, 1.0, 0.0, rad(0.01)).is_2d_eps(&1.0e-6));
+        assert!(Mf32::create_rotation(0.0, 1.0, 0.0, rad(0.01)).try_to_2d().is_none());
+        assert!(Mf32::create_translation(0.0, 0.0, 1.0).try_to_2d().is_none());
+        assert!(Mf32::create_perspective(100.0).try_to_2d().is_none());
+        assert!(Mf32::create_scale(1.0, 1.0, 1.0e-3).try_to_2d().is_none());
+    }
+
     #[test]
     pub fn test_row_major_2d() {
         let m1 = Mf32::row_major_2d(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
@@ -877,6 +1179,54 @@ mod tests {
         assert!(t.pre_mul(&r).transform_point3d(&a).approx_eq(&t.transform_point3d(&r.transform_point3d(&a))));
     }
 
+    #[test]
+    pub fn test_row_major_column_major() {
+        let row = Mf32::row_major(
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0,
+        );
+        let column = Mf32::column_major(
+            1.0, 5.0, 9.0, 13.0,
+            2.0, 6.0, 10.0, 14.0,
+            3.0, 7.0, 11.0, 15.0,
+            4.0, 8.0, 12.0, 16.0,
+        );
+        assert_eq!(row, column);
+        assert_eq!((row.m12, row.m21, row.m34, row.m43), (2.0, 5.0, 12.0, 15.0));
+        assert_eq!(Mf32::from_array(row.to_row_major_array()), row);
+
+        // Skewing along x by 45 degrees shifts x by y.
+        let skew = Mf32::create_skew(rad(PI / 4.0), rad(0.0));
+        assert!(skew.transform_point3d(&Point3D::new(0.0, 2.0, 0.0)).approx_eq(&Point3D::new(2.0, 2.0, 0.0)));
+        assert!(skew.to_2d().approx_eq(&Transform2D::row_major(skew.m11, skew.m12, skew.m21, skew.m22, 0.0, 0.0)));
+    }
+
+    #[test]
+    pub fn test_rows_columns_and_axes() {
+        let m = Mf32::create_scale(2.0, 3.0, 4.0).post_translate(vec3(5.0, 6.0, 7.0));
+
+        assert_eq!(m.x_axis(), vec3(2.0, 0.0, 0.0));
+        assert_eq!(m.y_axis(), vec3(0.0, 3.0, 0.0));
+        assert_eq!(m.z_axis(), vec3(0.0, 0.0, 4.0));
+        assert_eq!(m.translation(), vec3(5.0, 6.0, 7.0));
+
+        assert_eq!(m.row(1), [0.0, 3.0, 0.0, 0.0]);
+        assert_eq!(m.row(3), [5.0, 6.0, 7.0, 1.0]);
+        assert_eq!(m.column(0), [2.0, 0.0, 0.0, 5.0]);
+        assert_eq!(m.column(3), [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    pub fn test_flips_handedness() {
+        assert!(Mf32::create_scale(-1.0, 1.0, 1.0).flips_handedness());
+        assert!(Mf32::create_scale(1.0, 1.0, -2.0).post_translate(vec3(1.0, 2.0, 3.0)).flips_handedness());
+        assert!(!Mf32::create_scale(-1.0, -1.0, 1.0).flips_handedness());
+        assert!(!Mf32::create_rotation(0.0, 1.0, 0.0, rad(FRAC_PI_2)).flips_handedness());
+        assert!(!Mf32::create_perspective(100.0).flips_handedness());
+    }
+
     #[test]
     fn test_size_of() {
         use std::mem::size_of;
@@ -907,6 +1257,10 @@ mod tests {
         assert!(m1.is_identity());
         let m2 = m1.post_translate(vec3(0.1, 0.0, 0.0));
         assert!(!m2.is_identity());
+
+        let m = Mf32::create_perspective(10.0).post_translate(vec3(1.0, 2.0, 3.0));
+        assert_eq!(m.post_mul(&Mf32::identity()), m);
+        assert_eq!(m.pre_mul(&Mf32::identity()), m);
     }
 
     #[test]
@@ -942,4 +1296,180 @@ mod tests {
         let r1 = Mf32::create_scale(2.0, 0.0, 2.0);
         assert!(!r1.is_backface_visible());
     }
+
+    #[test]
+    pub fn test_transform_normal() {
+        let m = Mf32::create_scale(2.0, 1.0, 1.0);
+
+        let n = m.transform_normal(vec3(1.0, 0.0, 0.0)).unwrap();
+        assert!(n.approx_eq(&vec3(1.0, 0.0, 0.0)));
+
+        // The face x + y = 1 becomes x + 2y = 2 after scaling x by 2.
+        let s = 0.5f32.sqrt();
+        let n = m.transform_normal(vec3(s, s, 0.0)).unwrap();
+        let expected = vec3(1.0, 2.0, 0.0).normalize();
+        assert!(n.approx_eq(&expected));
+        // It stays perpendicular to the transformed face, unlike the naive transform.
+        let tangent = m.transform_vector3d(&vec3(1.0, -1.0, 0.0));
+        assert!(n.dot(tangent).approx_eq(&0.0));
+        assert!(!m.transform_vector3d(&vec3(s, s, 0.0)).dot(tangent).approx_eq(&0.0));
+
+        // Mirroring keeps the normal on the correct side.
+        let n = Mf32::create_scale(-1.0, 1.0, 1.0).transform_normal(vec3(0.0, 0.0, 1.0)).unwrap();
+        assert!(n.approx_eq(&vec3(0.0, 0.0, 1.0)));
+
+        assert!(Mf32::create_scale(2.0, 0.0, 1.0).transform_normal(vec3(1.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    pub fn test_frustum_planes() {
+        // OpenGL-style perspective projection with a 90 degree field of view, looking
+        // down the negative z axis, with the near and far planes at 1 and 100.
+        let (near, far) = (1.0, 100.0);
+        let m = Mf32::row_major(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, (far + near) / (near - far), -1.0,
+            0.0, 0.0, 2.0 * far * near / (near - far), 0.0,
+        );
+        let planes = m.frustum_planes();
+
+        let inside = point3(0.5, -0.5, -10.0);
+        for plane in &planes {
+            assert!(plane.signed_distance(&inside) > 0.0);
+        }
+
+        let before_near = point3(0.0, 0.0, -0.5);
+        assert!(planes[4].signed_distance(&before_near) < 0.0);
+        assert!(planes[4].signed_distance(&before_near).approx_eq(&-0.5));
+
+        let beyond_far = point3(0.0, 0.0, -150.0);
+        assert!(planes[5].signed_distance(&beyond_far) < 0.0);
+
+        let left = point3(-20.0, 0.0, -10.0);
+        assert!(planes[0].signed_distance(&left) < 0.0);
+        assert!(planes[1].signed_distance(&left) > 0.0);
+    }
+
+    #[test]
+    pub fn test_unproject() {
+        let eps = point3(1.0e-4, 1.0e-4, 1.0e-4);
+        let ortho = Mf32::ortho(-10.0, 30.0, -5.0, 15.0, 1.0, 100.0);
+        for p in &[point3(0.0, 0.0, -1.0), point3(-10.0, 15.0, -50.0), point3(29.0, -4.0, -99.0)] {
+            let ndc = ortho.transform_point3d(p);
+            assert!(ortho.unproject(&ndc).unwrap().approx_eq_eps(p, &eps));
+        }
+        assert!(ortho.unproject(&point3(-1.0, 1.0, -1.0)).unwrap().approx_eq_eps(&point3(-10.0, 15.0, -1.0), &eps));
+
+        let (near, far) = (1.0, 100.0);
+        let perspective = Mf32::row_major(
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, (far + near) / (near - far), -1.0,
+            0.0, 0.0, 2.0 * far * near / (near - far), 0.0,
+        );
+        for p in &[point3(0.0, 0.0, -2.0), point3(0.5, -0.25, -1.0), point3(3.0, 4.0, -10.0)] {
+            let ndc = perspective.transform_point3d(p);
+            let back = perspective.unproject(&ndc).unwrap();
+            assert!(back.approx_eq_eps(p, &eps));
+        }
+
+        // Picking ray through the center of the screen.
+        let near_point = perspective.unproject(&point3(0.0, 0.0, -1.0)).unwrap();
+        let far_point = perspective.unproject(&point3(0.0, 0.0, 1.0)).unwrap();
+        assert!(near_point.approx_eq_eps(&point3(0.0, 0.0, -1.0), &eps));
+        assert!(far_point.approx_eq_eps(&point3(0.0, 0.0, -100.0), &point3(1.0e-3, 1.0e-3, 1.0e-3)));
+
+        assert!(Mf32::create_scale(1.0, 0.0, 1.0).unproject(&point3(0.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    pub fn test_then() {
+        let t = Mf32::create_translation(1.0, 2.0, 3.0)
+            .then_rotate(0.0, 0.0, 1.0, rad(FRAC_PI_2))
+            .then_scale(2.0, 2.0, 2.0)
+            .then_translate(vec3(0.0, 0.0, 1.0));
+        let expected = Mf32::create_translation(1.0, 2.0, 3.0)
+            .post_rotate(0.0, 0.0, 1.0, rad(FRAC_PI_2))
+            .post_scale(2.0, 2.0, 2.0)
+            .post_translate(vec3(0.0, 0.0, 1.0));
+        assert_eq!(t, expected);
+        assert!(t.transform_point3d(&point3(0.0, 0.0, 0.0)).approx_eq(&point3(4.0, -2.0, 7.0)));
+
+        let scale: TypedScale<f32, UnknownUnit, UnknownUnit> = TypedScale::new(3.0);
+        assert_eq!(t.then_scale_by(scale), t.then(&Mf32::create_scale(3.0, 3.0, 3.0)));
+    }
+
+    #[test]
+    pub fn test_convert_handedness() {
+        let m = Mf32::create_rotation(0.0, 1.0, 0.0, rad(0.5))
+            .post_translate(vec3(1.0, 2.0, 3.0))
+            .post_mul(&Mf32::create_perspective(50.0));
+        let converted = m.convert_handedness();
+
+        let p = point3(4.0, -5.0, 6.0);
+        assert!(converted.transform_point3d(&p.flip_handedness())
+            .approx_eq(&m.transform_point3d(&p).flip_handedness()));
+        assert_eq!(converted.convert_handedness(), m);
+
+        // A rotation around the y axis turns the other way in the mirrored system...
+        assert!(Mf32::create_rotation(0.0, 1.0, 0.0, rad(0.5)).convert_handedness()
+            .approx_eq(&Mf32::create_rotation(0.0, 1.0, 0.0, rad(-0.5))));
+        // ...while a rotation around the flipped axis is unchanged.
+        assert!(Mf32::create_rotation(0.0, 0.0, 1.0, rad(0.5)).convert_handedness()
+            .approx_eq(&Mf32::create_rotation(0.0, 0.0, 1.0, rad(0.5))));
+    }
+
+    #[test]
+    pub fn test_css_string() {
+        let m = Mf32::create_rotation(0.0, 0.0, 1.0, rad(0.5)).post_translate(vec3(10.0, -20.5, 3.0));
+        let css = m.to_css_string();
+        assert!(css.starts_with("matrix3d("));
+        assert_eq!(Mf32::from_css_string(&css), Ok(m));
+
+        let t = Mf32::create_translation(10.0, 20.0, 30.0);
+        assert_eq!(t.to_css_string(), "matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 10, 20, 30, 1)");
+        assert_eq!(Mf32::from_css_string(" MATRIX3D(1,0,0,0, 0,1,0,0, 0,0,1,0, 10,20,30,1) "), Ok(t));
+
+        assert_eq!(Mf32::from_css_string("matrix(1, 0, 0, 1, 0, 0)"), Err(ParseError::InvalidFunction));
+        assert_eq!(Mf32::from_css_string("matrix3d(1, 0, 0, 1)"), Err(ParseError::InvalidArgumentCount));
+        assert_eq!(
+            Mf32::from_css_string("matrix3d(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 10px, 20, 30, 1)"),
+            Err(ParseError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    pub fn test_transform_rect() {
+        let r = rect(-10.0, -20.0, 30.0, 40.0);
+        let m = Mf32::create_scale(2.0, 3.0, 1.0).post_translate(vec3(1.0, 2.0, 3.0));
+        assert_eq!(m.transform_rect(&r), Some(rect(-19.0, -58.0, 60.0, 120.0)));
+
+        // The bounds of the projected corners.
+        let m = Mf32::create_rotation(0.0, 1.0, 0.0, rad(FRAC_PI_4)).post_mul(&Mf32::create_perspective(100.0));
+        let corners = [
+            m.transform_point2d(&r.origin),
+            m.transform_point2d(&r.top_right()),
+            m.transform_point2d(&r.bottom_left()),
+            m.transform_point2d(&r.bottom_right()),
+        ];
+        let bounds = m.transform_rect(&r).unwrap();
+        let expected = TypedRect::from_points(&corners);
+        assert!(bounds.origin.approx_eq(&expected.origin));
+        assert!(bounds.bottom_right().approx_eq(&expected.bottom_right()));
+
+        // Part of this rectangle goes behind the camera plane.
+        let wide = rect(-500.0, -20.0, 1000.0, 40.0);
+        assert_eq!(m.transform_rect(&wide), None);
+
+        // w is exactly zero at the right edge.
+        let m = Mf32::row_major(
+            1.0, 0.0, 0.0, -0.1,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        );
+        assert!(m.transform_rect(&rect(0.0, 0.0, 9.0, 1.0)).is_some());
+        assert_eq!(m.transform_rect(&rect(0.0, 0.0, 10.0, 1.0)), None);
+    }
 }
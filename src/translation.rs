@@ -0,0 +1,309 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::UnknownUnit;
+use num::{One, Zero};
+use point::{TypedPoint2D, TypedPoint3D, point2, point3};
+use rect::TypedRect;
+use size::TypedSize2D;
+use transform::Transform;
+use transform2d::TypedTransform2D;
+use transform3d::TypedTransform3D;
+use trig::Trig;
+use vector::{TypedVector2D, TypedVector3D, vec2, vec3};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::marker::PhantomData;
+
+define_matrix! {
+    /// A 2d transformation from a space to another that can only express translations.
+    ///
+    /// This is cheaper to store and to apply than a `TypedTransform2D`, and keeps the
+    /// guarantee that the transformation is only a translation. Composing two
+    /// translations with `*` gives a translation that applies the first one and then
+    /// the second one.
+    pub struct TypedTranslation2D<T, Src, Dst> {
+        pub x: T,
+        pub y: T,
+    }
+}
+
+/// The default 2d translation type with no units.
+pub type Translation2D<T> = TypedTranslation2D<T, UnknownUnit, UnknownUnit>;
+
+impl<T, Src, Dst> TypedTranslation2D<T, Src, Dst> {
+    /// Creates a translation from its x and y offsets.
+    #[inline]
+    pub const fn new(x: T, y: T) -> Self {
+        TypedTranslation2D {
+            x: x,
+            y: y,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Creates a translation by a vector expressed in the source space.
+    #[inline]
+    pub fn from_vector(v: TypedVector2D<T, Src>) -> Self {
+        Self::new(v.x, v.y)
+    }
+
+    /// Creates the identity translation.
+    #[inline]
+    pub fn identity() -> Self where T: Zero {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+impl<T: Copy, Src, Dst> TypedTranslation2D<T, Src, Dst> {
+    /// Returns the offset of this translation as a vector.
+    #[inline]
+    pub fn to_vector(&self) -> TypedVector2D<T, Src> {
+        vec2(self.x, self.y)
+    }
+
+    /// Returns the given point translated by this translation.
+    #[inline]
+    pub fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst>
+    where T: Add<T, Output=T> {
+        point2(point.x + self.x, point.y + self.y)
+    }
+
+    /// Returns the given rectangle translated by this translation. The size of the
+    /// rectangle is unchanged.
+    #[inline]
+    pub fn transform_rect(&self, rect: &TypedRect<T, Src>) -> TypedRect<T, Dst>
+    where T: Add<T, Output=T> {
+        TypedRect::new(
+            self.transform_point(&rect.origin),
+            TypedSize2D::new(rect.size.width, rect.size.height),
+        )
+    }
+
+    /// Returns the inverse translation.
+    #[inline]
+    pub fn inverse(&self) -> TypedTranslation2D<T, Dst, Src> where T: Neg<Output=T> {
+        TypedTranslation2D::new(-self.x, -self.y)
+    }
+}
+
+impl<T, Src, Dst> TypedTranslation2D<T, Src, Dst>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Sub<T, Output=T> +
+         Trig +
+         PartialOrd +
+         One + Zero {
+    /// Returns the matrix representation of this translation.
+    #[inline]
+    pub fn to_transform(&self) -> TypedTransform2D<T, Src, Dst> {
+        TypedTransform2D::create_translation(self.x, self.y)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, A, B, C>
+Mul<TypedTranslation2D<T, B, C>> for TypedTranslation2D<T, A, B> {
+    type Output = TypedTranslation2D<T, A, C>;
+    #[inline]
+    fn mul(self, other: TypedTranslation2D<T, B, C>) -> TypedTranslation2D<T, A, C> {
+        TypedTranslation2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, Src, Dst> Transform<T, Src, Dst> for TypedTranslation2D<T, Src, Dst> {
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        TypedTranslation2D::transform_point(self, point)
+    }
+
+    /// Vectors are not affected by translations, so this only changes the unit.
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        vec2(vector.x, vector.y)
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for TypedTranslation2D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Translation({:?},{:?})", self.x, self.y)
+    }
+}
+
+define_matrix! {
+    /// A 3d transformation from a space to another that can only express translations.
+    ///
+    /// See `TypedTranslation2D`.
+    pub struct TypedTranslation3D<T, Src, Dst> {
+        pub x: T,
+        pub y: T,
+        pub z: T,
+    }
+}
+
+/// The default 3d translation type with no units.
+pub type Translation3D<T> = TypedTranslation3D<T, UnknownUnit, UnknownUnit>;
+
+impl<T, Src, Dst> TypedTranslation3D<T, Src, Dst> {
+    /// Creates a translation from its x, y and z offsets.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        TypedTranslation3D {
+            x: x,
+            y: y,
+            z: z,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Creates a translation by a vector expressed in the source space.
+    #[inline]
+    pub fn from_vector(v: TypedVector3D<T, Src>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+
+    /// Creates the identity translation.
+    #[inline]
+    pub fn identity() -> Self where T: Zero {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Copy, Src, Dst> TypedTranslation3D<T, Src, Dst> {
+    /// Returns the offset of this translation as a vector.
+    #[inline]
+    pub fn to_vector(&self) -> TypedVector3D<T, Src> {
+        vec3(self.x, self.y, self.z)
+    }
+
+    /// Returns the given point translated by this translation.
+    #[inline]
+    pub fn transform_point3d(&self, point: &TypedPoint3D<T, Src>) -> TypedPoint3D<T, Dst>
+    where T: Add<T, Output=T> {
+        point3(point.x + self.x, point.y + self.y, point.z + self.z)
+    }
+
+    /// Returns the given 2d point translated by the x and y components of this
+    /// translation.
+    #[inline]
+    pub fn transform_point2d(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst>
+    where T: Add<T, Output=T> {
+        point2(point.x + self.x, point.y + self.y)
+    }
+
+    /// Returns the given rectangle translated by the x and y components of this
+    /// translation. The size of the rectangle is unchanged.
+    #[inline]
+    pub fn transform_rect(&self, rect: &TypedRect<T, Src>) -> TypedRect<T, Dst>
+    where T: Add<T, Output=T> {
+        TypedRect::new(
+            self.transform_point2d(&rect.origin),
+            TypedSize2D::new(rect.size.width, rect.size.height),
+        )
+    }
+
+    /// Returns the inverse translation.
+    #[inline]
+    pub fn inverse(&self) -> TypedTranslation3D<T, Dst, Src> where T: Neg<Output=T> {
+        TypedTranslation3D::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T, Src, Dst> TypedTranslation3D<T, Src, Dst>
+where T: Copy + Clone +
+         Add<T, Output=T> +
+         Sub<T, Output=T> +
+         Mul<T, Output=T> +
+         Div<T, Output=T> +
+         Neg<Output=T> +
+         PartialOrd +
+         Trig +
+         One + Zero {
+    /// Returns the matrix representation of this translation.
+    #[inline]
+    pub fn to_transform(&self) -> TypedTransform3D<T, Src, Dst> {
+        TypedTransform3D::create_translation(self.x, self.y, self.z)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, A, B, C>
+Mul<TypedTranslation3D<T, B, C>> for TypedTranslation3D<T, A, B> {
+    type Output = TypedTranslation3D<T, A, C>;
+    #[inline]
+    fn mul(self, other: TypedTranslation3D<T, B, C>) -> TypedTranslation3D<T, A, C> {
+        TypedTranslation3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, Src, Dst> Transform<T, Src, Dst> for TypedTranslation3D<T, Src, Dst> {
+    /// Like `TypedTransform3D`, this ignores the z component of the translation.
+    #[inline]
+    fn transform_point(&self, point: &TypedPoint2D<T, Src>) -> TypedPoint2D<T, Dst> {
+        self.transform_point2d(point)
+    }
+
+    /// Vectors are not affected by translations, so this only changes the unit.
+    #[inline]
+    fn transform_vector(&self, vector: &TypedVector2D<T, Src>) -> TypedVector2D<T, Dst> {
+        vec2(vector.x, vector.y)
+    }
+}
+
+impl<T: fmt::Debug, Src, Dst> fmt::Debug for TypedTranslation3D<T, Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Translation({:?},{:?},{:?})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {Point2D, Point3D, Rect, Transform2D, Transform3D, rect, vec2, vec3};
+
+    #[test]
+    pub fn test_translation_2d() {
+        let t1: Translation2D<f32> = TypedTranslation2D::new(1.0, 2.0);
+        let t2: Translation2D<f32> = TypedTranslation2D::from_vector(vec2(10.0, -5.0));
+        assert_eq!(t1 * t2, TypedTranslation2D::new(11.0, -3.0));
+        assert_eq!((t1 * t2).to_vector(), t1.to_vector() + t2.to_vector());
+        assert_eq!(t1 * t1.inverse(), Translation2D::identity());
+
+        let p: Point2D<f32> = point2(3.0, 4.0);
+        assert_eq!(t1.transform_point(&p), point2(4.0, 6.0));
+        assert_eq!(t1.to_transform().transform_point(&p), t1.transform_point(&p));
+        assert_eq!(t1.to_transform(), Transform2D::create_translation(1.0, 2.0));
+
+        let r: Rect<f32> = rect(1.0, 1.0, 5.0, 6.0);
+        assert_eq!(t1.transform_rect(&r), rect(2.0, 3.0, 5.0, 6.0));
+        assert_eq!(t1.to_transform().transform_rect(&r), t1.transform_rect(&r));
+
+        assert_eq!(Transform::transform_vector(&t1, &vec2(1.0, 1.0)), vec2(1.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_translation_3d() {
+        let t1: Translation3D<f32> = TypedTranslation3D::new(1.0, 2.0, 3.0);
+        let t2: Translation3D<f32> = TypedTranslation3D::from_vector(vec3(10.0, -5.0, 0.5));
+        assert_eq!(t1 * t2, TypedTranslation3D::new(11.0, -3.0, 3.5));
+        assert_eq!(t1 * t1.inverse(), Translation3D::identity());
+
+        let p: Point3D<f32> = point3(3.0, 4.0, 5.0);
+        assert_eq!(t1.transform_point3d(&p), point3(4.0, 6.0, 8.0));
+        assert_eq!(t1.to_transform().transform_point3d(&p), t1.transform_point3d(&p));
+        assert_eq!(t1.to_transform(), Transform3D::create_translation(1.0, 2.0, 3.0));
+
+        let r: Rect<f32> = rect(1.0, 1.0, 5.0, 6.0);
+        assert_eq!(t1.transform_rect(&r), rect(2.0, 3.0, 5.0, 6.0));
+
+        let p: Point2D<f32> = point2(3.0, 4.0);
+        assert_eq!(Transform::transform_point(&t1, &p), point2(4.0, 6.0));
+        assert_eq!(Transform::transform_vector(&t1, &vec2(1.0, 1.0)), vec2(1.0, 1.0));
+    }
+}
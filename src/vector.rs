@@ -16,7 +16,7 @@ use scale::TypedScale;
 use trig::Trig;
 use Angle;
 use num::*;
-use num_traits::{Float, NumCast, Signed};
+use num_traits::{Float, FloatConst, NumCast, Signed};
 use std::fmt;
 use std::ops::{Add, Neg, Mul, Sub, Div, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::marker::PhantomData;
@@ -63,7 +63,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedVector2D<T, U> {
 impl<T, U> TypedVector2D<T, U> {
     /// Constructor taking scalar values directly.
     #[inline]
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         TypedVector2D { x: x, y: y, _unit: PhantomData }
     }
 }
@@ -130,7 +130,11 @@ impl<T: Copy, U> TypedVector2D<T, U> {
 
 impl<T, U> TypedVector2D<T, U>
 where T: Trig + Copy + Sub<T, Output = T> {
-    /// Returns the angle between this vector and the x axis between -PI and PI.
+    /// Returns the angle between this vector and the x axis, in the `(-PI, PI]` range.
+    ///
+    /// This is the heading of the vector, computed with an approximation of
+    /// `atan2(y, x)` (see `Trig::fast_atan2`). Vectors pointing along the negative
+    /// x axis return PI. The result is not meaningful for a zero vector.
     pub fn angle_from_x_axis(&self) -> Angle<T> {
         Angle::radians(Trig::fast_atan2(self.y, self.x))
     }
@@ -150,6 +154,23 @@ where T: Copy + Mul<T, Output=T> + Add<T, Output=T> + Sub<T, Output=T> {
         self.x * other.y - self.y * other.x
     }
 
+    /// Dot product, as a `Length`.
+    ///
+    /// Strictly speaking the result is in units squared. It is tagged with this
+    /// vector's unit so that computations can stay in the typed world.
+    #[inline]
+    pub fn dot_typed(self, other: Self) -> Length<T, U> {
+        Length::new(self.dot(other))
+    }
+
+    /// Same as `cross`, as a `Length`.
+    ///
+    /// Strictly speaking the result is in units squared, see `dot_typed`.
+    #[inline]
+    pub fn cross_typed(self, other: Self) -> Length<T, U> {
+        Length::new(self.cross(other))
+    }
+
     #[inline]
     pub fn normalize(self) -> Self where T: Float + ApproxEq<T> {
         let dot = self.dot(self);
@@ -169,6 +190,77 @@ where T: Copy + Mul<T, Output=T> + Add<T, Output=T> + Sub<T, Output=T> {
     pub fn length(&self) -> T where T: Float {
         self.square_length().sqrt()
     }
+
+    /// Same as `square_length`, as a `Length`.
+    ///
+    /// Strictly speaking the result is in units squared, see `dot_typed`.
+    #[inline]
+    pub fn square_length_typed(&self) -> Length<T, U> {
+        Length::new(self.square_length())
+    }
+
+    /// Same as `length`, as a `Length`.
+    #[inline]
+    pub fn length_typed(&self) -> Length<T, U> where T: Float {
+        Length::new(self.length())
+    }
+}
+
+/// Returns twice the signed area of the polygon formed by a sequence of points, which
+/// is the sum of the cross products of consecutive points.
+fn shoelace_sum<T, U>(points: &[TypedPoint2D<T, U>]) -> T
+where T: Copy + Zero + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+    if points.len() < 3 {
+        return T::zero();
+    }
+
+    let mut sum = T::zero();
+    for (i, p) in points.iter().enumerate() {
+        let next = points[(i + 1) % points.len()];
+        sum = sum + p.to_vector().cross(next.to_vector());
+    }
+    sum
+}
+
+/// Returns the signed area of the polygon formed by a sequence of points, using the
+/// shoelace formula.
+///
+/// The last point is connected back to the first one. The sign follows the 2d cross
+/// product: the area is positive if the points wind in the direction that rotates the
+/// x axis towards the y axis (counter-clockwise with y pointing up, clockwise with y
+/// pointing down), and negative otherwise.
+///
+/// For integer types the area is truncated towards zero, so polygons with an area
+/// smaller than one have an area of zero.
+///
+/// Returns zero if there are fewer than three points.
+pub fn signed_area<T, U>(points: &[TypedPoint2D<T, U>]) -> T
+where T: Copy + Zero + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> {
+    shoelace_sum(points) / (T::one() + T::one())
+}
+
+/// Returns true if the polygon formed by a sequence of points winds clockwise, assuming
+/// the y axis points up.
+///
+/// This is the case when the signed area is negative, see `signed_area` for the sign
+/// convention. The test is exact for integer types, including for polygons with an
+/// area smaller than one. Degenerate polygons are not clockwise.
+pub fn is_clockwise<T, U>(points: &[TypedPoint2D<T, U>]) -> bool
+where T: Copy + Zero + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+    shoelace_sum(points) < T::zero()
+}
+
+/// Returns true if the three points lie on a same line.
+///
+/// This checks whether the vectors from `a` to `b` and from `a` to `c` are parallel,
+/// see `TypedVector2D::is_parallel_to` for the meaning of `eps`.
+pub fn are_collinear<T: Float, U>(
+    a: TypedPoint2D<T, U>,
+    b: TypedPoint2D<T, U>,
+    c: TypedPoint2D<T, U>,
+    eps: T,
+) -> bool {
+    (b - a).is_parallel_to(&(c - a), eps)
 }
 
 impl<T, U> TypedVector2D<T, U>
@@ -220,15 +312,171 @@ impl <T: Copy + Neg<Output=T>, U> Neg for TypedVector2D<T, U> {
     }
 }
 
-impl<T: Float, U> TypedVector2D<T, U> {
+impl<T: Copy + Neg<Output=T>, U> TypedVector2D<T, U> {
+    /// Returns this vector rotated by 90 degrees counter-clockwise, that is `(-y, x)`.
+    ///
+    /// The rotation is counter-clockwise when the y axis points up, and appears
+    /// clockwise with the y axis pointing down as in screen coordinates. It is
+    /// exact, including for integer vectors, and the result has the same length.
+    #[inline]
+    pub fn perpendicular(&self) -> Self {
+        vec2(-self.y, self.x)
+    }
+
+    /// Returns this vector rotated by 90 degrees clockwise, that is `(y, -x)`.
+    ///
+    /// This is the opposite of `perpendicular`, see its documentation for the
+    /// orientation.
+    #[inline]
+    pub fn perpendicular_cw(&self) -> Self {
+        vec2(self.y, -self.x)
+    }
+}
+
+impl<T: Copy + PartialOrd, U> TypedVector2D<T, U> {
+    /// Returns the component-wise minimum of this vector and another one.
+    ///
+    /// Like `Float::min`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn min(self, other: Self) -> Self {
-         vec2(self.x.min(other.x), self.y.min(other.y))
+        vec2(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+        )
     }
 
+    /// Returns the component-wise maximum of this vector and another one.
+    ///
+    /// Like `Float::max`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn max(self, other: Self) -> Self {
-        vec2(self.x.max(other.x), self.y.max(other.y))
+        vec2(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+        )
+    }
+}
+
+impl<T: Float, U> TypedVector2D<T, U> {
+    /// Returns this vector rotated by the angle whose sine and cosine are `sin` and `cos`,
+    /// in the same direction as `TypedRotation2D`.
+    ///
+    /// This lets callers compute the trigonometry once when rotating many vectors by the
+    /// same angle.
+    #[inline]
+    pub fn rotate_by_sin_cos(&self, sin: T, cos: T) -> Self {
+        vec2(self.x * cos - self.y * sin, self.y * cos + self.x * sin)
+    }
+
+    /// Creates a vector from a polar angle, measured from the x axis towards the y axis,
+    /// and a length.
+    ///
+    /// This is the inverse of `to_polar`.
+    #[inline]
+    pub fn from_angle_and_length(angle: Angle<T>, length: T) -> Self {
+        let (sin, cos) = angle.get().sin_cos();
+        vec2(cos * length, sin * length)
+    }
+
+    /// Returns the polar coordinates of this vector: its length and its angle from the
+    /// x axis, in the `(-PI, PI]` range like `angle_from_x_axis`.
+    ///
+    /// Unlike `angle_from_x_axis` this uses an exact `atan2` so that
+    /// `from_angle_and_length` reconstructs the vector. The angle of a zero vector is
+    /// undefined; in that case the returned angle is zero.
+    #[inline]
+    pub fn to_polar(&self) -> (T, Angle<T>)
+    where T: FloatConst {
+        let zero = T::zero();
+        if self.x == zero && self.y == zero {
+            return (zero, Angle::radians(zero));
+        }
+        // atan2 returns -PI for a negative zero y, which is outside of the range.
+        let angle = self.y.atan2(self.x);
+        let angle = if angle == -T::PI() { T::PI() } else { angle };
+        (self.length(), Angle::radians(angle))
+    }
+
+    /// Returns the same vector with negative zero components replaced by positive zero,
+    /// so that values can be compared or hashed bitwise.
+    #[inline]
+    pub fn normalize_zero(&self) -> Self {
+        // Adding positive zero maps -0.0 to +0.0 and leaves other values unchanged.
+        let zero = T::zero();
+        vec2(self.x + zero, self.y + zero)
+    }
+
+    /// Returns true if this vector and `other` are equal up to a tolerance relative to
+    /// their magnitude. See `TypedPoint2D::approx_eq_relative`.
+    #[inline]
+    pub fn approx_eq_relative(&self, other: &Self, rel_eps: T) -> bool {
+        let scale = self.x.abs().max(self.y.abs()).max(other.x.abs()).max(other.y.abs());
+        let eps = rel_eps * scale;
+        (self.x - other.x).abs() <= eps && (self.y - other.y).abs() <= eps
+    }
+
+    /// Returns true if all of the components of this vector are finite, that is
+    /// neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns true if all of the components of this vector are within the
+    /// `ApproxEq` epsilon of zero.
+    #[inline]
+    pub fn approx_zero(&self) -> bool where T: ApproxEq<T> {
+        let zero = T::zero();
+        self.x.approx_eq(&zero) && self.y.approx_eq(&zero)
+    }
+}
+
+impl<T: Float, U> TypedVector2D<T, U> {
+    /// Returns true if this vector and `other` point in the same or in opposite
+    /// directions.
+    ///
+    /// `eps` is the tolerance on the sine of the angle between the two vectors.
+    /// A zero vector is parallel to any vector.
+    #[inline]
+    pub fn is_parallel_to(&self, other: &Self, eps: T) -> bool {
+        self.cross(*other).abs() <= eps * self.length() * other.length()
+    }
+
+    /// Returns true if this vector and `other` are perpendicular.
+    ///
+    /// `eps` is the tolerance on the cosine of the angle between the two vectors.
+    /// A zero vector is perpendicular to any vector.
+    #[inline]
+    pub fn is_perpendicular_to(&self, other: &Self, eps: T) -> bool {
+        self.dot(*other).abs() <= eps * self.length() * other.length()
+    }
+
+    /// Returns the signed length of the projection of this vector onto `axis`.
+    ///
+    /// `axis` does not need to be normalized. Returns zero if `axis` has a
+    /// length of zero.
+    #[inline]
+    pub fn component_along(&self, axis: Self) -> Length<T, U> {
+        let axis_length = axis.length();
+        if axis_length == T::zero() {
+            return Length::new(T::zero());
+        }
+        Length::new(self.dot(axis) / axis_length)
+    }
+
+    /// Returns the signed length of the component of this vector that is
+    /// perpendicular to `axis`.
+    ///
+    /// The result is positive if this vector points towards the side of `axis`
+    /// that the y axis is on relative to the x axis. Returns zero if `axis`
+    /// has a length of zero.
+    #[inline]
+    pub fn component_perpendicular(&self, axis: Self) -> Length<T, U> {
+        let axis_length = axis.length();
+        if axis_length == T::zero() {
+            return Length::new(T::zero());
+        }
+        Length::new(axis.cross(*self) / axis_length)
     }
 }
 
@@ -402,6 +650,9 @@ impl<T: Copy, U> From<[T; 2]> for TypedVector2D<T, U> {
     }
 }
 
+impl_byte_conversions!(TypedVector2D, 2, {x, y});
+impl_component_indexing!(TypedVector2D, 2, {0 => x, 1 => y});
+
 impl<T, U> TypedVector2D<T, U>
 where T: Signed {
     pub fn abs(&self) -> Self {
@@ -451,7 +702,7 @@ impl<T: fmt::Display, U> fmt::Display for TypedVector3D<T, U> {
 impl<T, U> TypedVector3D<T, U> {
     /// Constructor taking scalar values directly.
     #[inline]
-    pub fn new(x: T, y: T, z: T) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         TypedVector3D { x: x, y: y, z: z, _unit: PhantomData }
     }
 }
@@ -536,6 +787,14 @@ impl<T: Mul<T, Output=T> +
         self.z * other.z
     }
 
+    /// Dot product, as a `Length`.
+    ///
+    /// Strictly speaking the result is in units squared, see `TypedVector2D::dot_typed`.
+    #[inline]
+    pub fn dot_typed(self, other: Self) -> Length<T, U> {
+        Length::new(self.dot(other))
+    }
+
     // Cross product.
     #[inline]
     pub fn cross(self, other: Self) -> Self {
@@ -565,8 +824,47 @@ impl<T: Mul<T, Output=T> +
     pub fn length(&self) -> T where T: Float + ApproxEq<T> {
         self.square_length().sqrt()
     }
+
+    /// Same as `square_length`, as a `Length`.
+    ///
+    /// Strictly speaking the result is in units squared, see `dot_typed`.
+    #[inline]
+    pub fn square_length_typed(&self) -> Length<T, U> {
+        Length::new(self.square_length())
+    }
+
+    /// Same as `length`, as a `Length`.
+    #[inline]
+    pub fn length_typed(&self) -> Length<T, U> where T: Float + ApproxEq<T> {
+        Length::new(self.length())
+    }
 }
 
+macro_rules! impl_square_length_widened {
+    ($name:ident, $fields:tt, $($ty:ty => $wide:ty),+) => ($(
+        impl_square_length_widened!(@impl $name, $fields, $ty, $wide);
+    )+);
+    (@impl $name:ident, { $($field:ident),+ }, $ty:ty, $wide:ty) => (
+        impl<U> $name<$ty, U> {
+            /// Returns the squared length of this vector, computed and returned with a
+            /// wider unsigned integer type so that, unlike `square_length`, it cannot
+            /// overflow.
+            ///
+            /// 8 bit components produce a `u32` and 16 bit components a `u64`. The type
+            /// twice as wide as the components would hold one square but not the sum of
+            /// several, for example `2 * 255 * 255` overflows a `u16`.
+            #[inline]
+            pub fn square_length_widened(&self) -> $wide {
+                let sum: i64 = [$(self.$field as i64),+].iter().map(|c| c * c).sum();
+                sum as $wide
+            }
+        }
+    );
+}
+
+impl_square_length_widened!(TypedVector2D, {x, y}, i8 => u32, u8 => u32, i16 => u64, u16 => u64);
+impl_square_length_widened!(TypedVector3D, {x, y, z}, i8 => u32, u8 => u32, i16 => u64, u16 => u64);
+
 impl<T, U> TypedVector3D<T, U>
 where T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
     /// Linearly interpolate between this vector and another vector.
@@ -647,15 +945,67 @@ impl<T: Copy + Div<T, Output=T>, U> DivAssign<T> for TypedVector3D<T, U> {
     }
 }
 
-impl<T: Float, U> TypedVector3D<T, U> {
+impl<T: Copy + PartialOrd, U> TypedVector3D<T, U> {
+    /// Returns the component-wise minimum of this vector and another one.
+    ///
+    /// Like `Float::min`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn min(self, other: Self) -> Self {
-         vec3(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+        vec3(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+            partial_min(self.z, other.z),
+        )
     }
 
+    /// Returns the component-wise maximum of this vector and another one.
+    ///
+    /// Like `Float::max`, a NaN component is ignored in favor of the other one.
     #[inline]
     pub fn max(self, other: Self) -> Self {
-        vec3(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+        vec3(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+            partial_max(self.z, other.z),
+        )
+    }
+}
+
+impl<T: Float, U> TypedVector3D<T, U> {
+    /// Returns the same vector with negative zero components replaced by positive zero,
+    /// so that values can be compared or hashed bitwise.
+    #[inline]
+    pub fn normalize_zero(&self) -> Self {
+        // Adding positive zero maps -0.0 to +0.0 and leaves other values unchanged.
+        let zero = T::zero();
+        vec3(self.x + zero, self.y + zero, self.z + zero)
+    }
+
+    /// Returns true if this vector and `other` are equal up to a tolerance relative to
+    /// their magnitude. See `TypedPoint2D::approx_eq_relative`.
+    #[inline]
+    pub fn approx_eq_relative(&self, other: &Self, rel_eps: T) -> bool {
+        let scale = self.x.abs().max(self.y.abs()).max(self.z.abs())
+            .max(other.x.abs()).max(other.y.abs()).max(other.z.abs());
+        let eps = rel_eps * scale;
+        (self.x - other.x).abs() <= eps &&
+            (self.y - other.y).abs() <= eps &&
+            (self.z - other.z).abs() <= eps
+    }
+
+    /// Returns true if all of the components of this vector are finite, that is
+    /// neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns true if all of the components of this vector are within the
+    /// `ApproxEq` epsilon of zero.
+    #[inline]
+    pub fn approx_zero(&self) -> bool where T: ApproxEq<T> {
+        let zero = T::zero();
+        self.x.approx_eq(&zero) && self.y.approx_eq(&zero) && self.z.approx_eq(&zero)
     }
 }
 
@@ -786,6 +1136,9 @@ impl<T: Copy, U> From<[T; 3]> for TypedVector3D<T, U> {
     }
 }
 
+impl_byte_conversions!(TypedVector3D, 3, {x, y, z});
+impl_component_indexing!(TypedVector3D, 3, {0 => x, 1 => y, 2 => z});
+
 impl<T, U> TypedVector3D<T, U>
 where T: Signed {
     pub fn abs(&self) -> Self {
@@ -793,6 +1146,280 @@ where T: Signed {
     }
 }
 
+define_matrix! {
+    /// A 4d Vector tagged with a unit.
+    ///
+    /// This is useful for four-component data such as homogeneous coordinates.
+    pub struct TypedVector4D<T, U> {
+        pub x: T,
+        pub y: T,
+        pub z: T,
+        pub w: T,
+    }
+}
+
+/// Default 4d vector type with no unit.
+///
+/// `Vector4D` provides the same methods as `TypedVector4D`.
+pub type Vector4D<T> = TypedVector4D<T, UnknownUnit>;
+
+impl<T: Copy + Zero, U> TypedVector4D<T, U> {
+    /// Constructor, setting all components to zero.
+    #[inline]
+    pub fn zero() -> Self {
+        vec4(Zero::zero(), Zero::zero(), Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for TypedVector4D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:?},{:?},{:?},{:?})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl<T: fmt::Display, U> fmt::Display for TypedVector4D<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{},{},{})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl<T, U> TypedVector4D<T, U> {
+    /// Constructor taking scalar values directly.
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        TypedVector4D { x: x, y: y, z: z, w: w, _unit: PhantomData }
+    }
+}
+
+impl<T: Copy, U> TypedVector4D<T, U> {
+    /// Returns a 3d vector using this vector's x, y and z coordinates, dropping w.
+    #[inline]
+    pub fn to_3d(&self) -> TypedVector3D<T, U> {
+        vec3(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    pub fn to_array(&self) -> [T; 4] { [self.x, self.y, self.z, self.w] }
+
+    /// Drop the units, preserving only the numeric value.
+    #[inline]
+    pub fn to_untyped(&self) -> Vector4D<T> {
+        vec4(self.x, self.y, self.z, self.w)
+    }
+
+    /// Tag a unitless value with units.
+    #[inline]
+    pub fn from_untyped(p: &Vector4D<T>) -> Self {
+        vec4(p.x, p.y, p.z, p.w)
+    }
+}
+
+impl<T: Copy, U> TypedVector3D<T, U> {
+    /// Create a 4d vector from this one, using the specified w value.
+    #[inline]
+    pub fn extend(&self, w: T) -> TypedVector4D<T, U> {
+        vec4(self.x, self.y, self.z, w)
+    }
+}
+
+impl<T: Mul<T, Output=T> +
+        Add<T, Output=T> +
+        Sub<T, Output=T> +
+        Copy, U> TypedVector4D<T, U> {
+
+    /// Dot product.
+    #[inline]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x +
+        self.y * other.y +
+        self.z * other.z +
+        self.w * other.w
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self where T: Float + ApproxEq<T> {
+        let dot = self.dot(self);
+        if dot.approx_eq(&T::zero()) {
+            self
+        } else {
+            self / dot.sqrt()
+        }
+    }
+
+    #[inline]
+    pub fn square_length(&self) -> T {
+        self.dot(*self)
+    }
+
+    #[inline]
+    pub fn length(&self) -> T where T: Float {
+        self.square_length().sqrt()
+    }
+}
+
+impl<T, U> TypedVector4D<T, U>
+where T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> {
+    /// Linearly interpolate between this vector and another vector.
+    ///
+    /// `t` is expected to be between zero and one.
+    #[inline]
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        let one_t = T::one() - t;
+        (*self) * one_t + other * t
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, U> Add for TypedVector4D<T, U> {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        vec4(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
+    }
+}
+
+impl<T: Copy + Sub<T, Output=T>, U> Sub for TypedVector4D<T, U> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        vec4(self.x - other.x, self.y - other.y, self.z - other.z, self.w - other.w)
+    }
+}
+
+impl<T: Copy + Add<T, Output=T>, U> AddAssign for TypedVector4D<T, U> {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other
+    }
+}
+
+impl<T: Copy + Sub<T, Output=T>, U> SubAssign for TypedVector4D<T, U> {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other
+    }
+}
+
+impl<T: Copy + Neg<Output=T>, U> Neg for TypedVector4D<T, U> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        vec4(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl<T: Copy + Mul<T, Output=T>, U> Mul<T> for TypedVector4D<T, U> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, scale: T) -> Self {
+        vec4(self.x * scale, self.y * scale, self.z * scale, self.w * scale)
+    }
+}
+
+impl<T: Copy + Div<T, Output=T>, U> Div<T> for TypedVector4D<T, U> {
+    type Output = Self;
+    #[inline]
+    fn div(self, scale: T) -> Self {
+        vec4(self.x / scale, self.y / scale, self.z / scale, self.w / scale)
+    }
+}
+
+impl<T: Copy + Mul<T, Output=T>, U> MulAssign<T> for TypedVector4D<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, scale: T) {
+        *self = *self * scale
+    }
+}
+
+impl<T: Copy + Div<T, Output=T>, U> DivAssign<T> for TypedVector4D<T, U> {
+    #[inline]
+    fn div_assign(&mut self, scale: T) {
+        *self = *self / scale
+    }
+}
+
+impl<T: Copy + PartialOrd, U> TypedVector4D<T, U> {
+    /// Returns the component-wise minimum of this vector and another one.
+    ///
+    /// Like `Float::min`, a NaN component is ignored in favor of the other one.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        vec4(
+            partial_min(self.x, other.x),
+            partial_min(self.y, other.y),
+            partial_min(self.z, other.z),
+            partial_min(self.w, other.w),
+        )
+    }
+
+    /// Returns the component-wise maximum of this vector and another one.
+    ///
+    /// Like `Float::max`, a NaN component is ignored in favor of the other one.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        vec4(
+            partial_max(self.x, other.x),
+            partial_max(self.y, other.y),
+            partial_max(self.z, other.z),
+            partial_max(self.w, other.w),
+        )
+    }
+}
+
+impl<T: NumCast + Copy, U> TypedVector4D<T, U> {
+    /// Cast from one numeric representation to another, preserving the units.
+    ///
+    /// When casting from floating vector to integer coordinates, the decimals are truncated
+    /// as one would expect from a simple cast, but this behavior does not always make sense
+    /// geometrically. Consider using `round()`, `ceil()` or `floor()` before casting.
+    #[inline]
+    pub fn cast<NewT: NumCast + Copy>(&self) -> Option<TypedVector4D<NewT, U>> {
+        match (NumCast::from(self.x), NumCast::from(self.y),
+               NumCast::from(self.z), NumCast::from(self.w)) {
+            (Some(x), Some(y), Some(z), Some(w)) => Some(vec4(x, y, z, w)),
+            _ => None
+        }
+    }
+}
+
+impl<T: Copy+ApproxEq<T>, U> ApproxEq<TypedVector4D<T, U>> for TypedVector4D<T, U> {
+    #[inline]
+    fn approx_epsilon() -> Self {
+        vec4(T::approx_epsilon(), T::approx_epsilon(), T::approx_epsilon(), T::approx_epsilon())
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.x.approx_eq(&other.x)
+            && self.y.approx_eq(&other.y)
+            && self.z.approx_eq(&other.z)
+            && self.w.approx_eq(&other.w)
+    }
+
+    #[inline]
+    fn approx_eq_eps(&self, other: &Self, eps: &Self) -> bool {
+        self.x.approx_eq_eps(&other.x, &eps.x)
+            && self.y.approx_eq_eps(&other.y, &eps.y)
+            && self.z.approx_eq_eps(&other.z, &eps.z)
+            && self.w.approx_eq_eps(&other.w, &eps.w)
+    }
+}
+
+impl<T: Copy, U> From<[T; 4]> for TypedVector4D<T, U> {
+    fn from(array: [T; 4]) -> Self {
+        vec4(array[0], array[1], array[2], array[3])
+    }
+}
+
+impl_byte_conversions!(TypedVector4D, 4, {x, y, z, w});
+impl_component_indexing!(TypedVector4D, 4, {0 => x, 1 => y, 2 => z, 3 => w});
+
+impl<T, U> TypedVector4D<T, U>
+where T: Signed {
+    pub fn abs(&self) -> Self {
+        vec4(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
+    }
+}
+
 /// Convenience constructor.
 #[inline]
 pub fn vec2<T, U>(x: T, y: T) -> TypedVector2D<T, U> {
@@ -805,11 +1432,110 @@ pub fn vec3<T, U>(x: T, y: T, z: T) -> TypedVector3D<T, U> {
     TypedVector3D::new(x, y, z)
 }
 
+/// Convenience constructor.
+#[inline]
+pub fn vec4<T, U>(x: T, y: T, z: T, w: T) -> TypedVector4D<T, U> {
+    TypedVector4D::new(x, y, z, w)
+}
+
 #[cfg(test)]
 mod vector2d {
     use super::{Vector2D, vec2};
     type Vec2 = Vector2D<f32>;
 
+    #[test]
+    pub fn test_bytes() {
+        let v: Vec2 = vec2(1.0, -2.0);
+        let bytes = v.to_le_bytes();
+        assert_eq!(&bytes[..4], &1.0f32.to_le_bytes());
+        assert_eq!(&bytes[4..], &(-2.0f32).to_le_bytes());
+        assert_eq!(Vec2::from_le_bytes(bytes), v);
+        assert_eq!(Vec2::from_ne_bytes(v.to_ne_bytes()), v);
+
+        let v: Vector2D<u16> = vec2(0x0102, 0x0304);
+        assert_eq!(v.to_le_bytes(), [2, 1, 4, 3]);
+    }
+
+    #[test]
+    pub fn test_normalize_zero() {
+        let v: Vec2 = vec2(-0.0, 1.0).normalize_zero();
+        assert!(v.x.is_sign_positive());
+        assert_eq!(v, vec2(0.0, 1.0));
+
+        let v: Vec2 = vec2(-2.0, -0.0).normalize_zero();
+        assert_eq!(v.x, -2.0);
+        assert!(v.y.is_sign_positive());
+    }
+
+    #[test]
+    pub fn test_square_length_widened() {
+        let v: Vector2D<i16> = vec2(-32768, 32767);
+        assert_eq!(v.square_length_widened(), 32768 * 32768 + 32767 * 32767);
+
+        // Larger than u16::MAX.
+        let v: Vector2D<u8> = vec2(255, 255);
+        assert_eq!(v.square_length_widened(), 2 * 255 * 255);
+
+        // Larger than u32::MAX.
+        let v: super::Vector3D<u16> = super::vec3(65535, 65535, 65535);
+        assert_eq!(v.square_length_widened(), 3 * 65535 * 65535);
+    }
+
+    #[test]
+    pub fn test_parallel_perpendicular() {
+        use super::are_collinear;
+        use point::{Point2D, point2};
+
+        let x: Vec2 = vec2(1.0, 0.0);
+        assert!(x.is_parallel_to(&vec2(2.0, 0.0), 1e-6));
+        assert!(x.is_parallel_to(&vec2(-3.0, 0.0), 1e-6));
+        assert!(!x.is_parallel_to(&vec2(1.0, 0.1), 1e-6));
+        assert!(x.is_parallel_to(&vec2(1.0, 0.001), 0.01));
+
+        assert!(x.is_perpendicular_to(&vec2(0.0, 5.0), 1e-6));
+        assert!(!x.is_perpendicular_to(&vec2(0.1, 5.0), 1e-6));
+        let diagonal: Vec2 = vec2(1.0, 1.0);
+        assert!(diagonal.is_perpendicular_to(&vec2(-2.0, 2.0), 1e-6));
+
+        let a: Point2D<f32> = point2(0.0, 1.0);
+        assert!(are_collinear(a, point2(1.0, 3.0), point2(-2.0, -3.0), 1e-6));
+        assert!(!are_collinear(a, point2(1.0, 3.0), point2(2.0, 4.0), 1e-6));
+    }
+
+    #[test]
+    pub fn test_length_typed() {
+        use length::Length;
+        use UnknownUnit;
+
+        let v: Vec2 = vec2(3.0, 4.0);
+        let length: Length<f32, UnknownUnit> = v.length_typed();
+        assert_eq!(length.get(), 5.0);
+        assert_eq!(v.square_length_typed().get(), 25.0);
+    }
+
+    #[test]
+    pub fn test_dot_cross_typed() {
+        let a: Vec2 = vec2(1.0, 2.0);
+        let b: Vec2 = vec2(3.0, 4.0);
+        assert_eq!(a.dot_typed(b).get(), 11.0);
+        assert_eq!(a.cross_typed(b).get(), -2.0);
+    }
+
+    #[test]
+    pub fn test_component_along() {
+        use approxeq::ApproxEq;
+        let v: Vec2 = vec2(3.0, 4.0);
+
+        assert_eq!(v.component_along(vec2(1.0, 0.0)).get(), 3.0);
+        assert_eq!(v.component_along(vec2(10.0, 0.0)).get(), 3.0);
+        assert!(v.component_along(vec2(1.0, 1.0)).get().approx_eq(&(7.0 / 2.0f32.sqrt())));
+        assert_eq!(v.component_along(vec2(0.0, 0.0)).get(), 0.0);
+
+        assert_eq!(v.component_perpendicular(vec2(1.0, 0.0)).get(), 4.0);
+        assert_eq!(v.component_perpendicular(vec2(0.0, 1.0)).get(), -3.0);
+        assert_eq!(v.component_perpendicular(vec2(0.0, 0.0)).get(), 0.0);
+    }
+
     #[test]
     pub fn test_scalar_mul() {
         let p1: Vec2 = vec2(3.0, 5.0);
@@ -864,9 +1590,21 @@ mod vector2d {
         assert_eq!(result, vec2(2.0, 3.0));
     }
 
+    #[test]
+    pub fn test_min_max_nan() {
+        let p1: Vec2 = vec2(f32::NAN, 3.0);
+        let p2: Vec2 = vec2(2.0, 2.0);
+
+        assert_eq!(p1.min(p2), vec2(2.0, 2.0));
+        assert_eq!(p2.min(p1), vec2(2.0, 2.0));
+        assert_eq!(p1.max(p2), vec2(2.0, 3.0));
+        assert_eq!(p2.max(p1), vec2(2.0, 3.0));
+    }
+
     #[test]
     pub fn test_angle_from_x_axis() {
-        use std::f32::consts::FRAC_PI_2;
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        use UnknownUnit;
         use approxeq::ApproxEq;
 
         let right: Vec2 = vec2(10.0, 0.0);
@@ -876,6 +1614,133 @@ mod vector2d {
         assert!(right.angle_from_x_axis().get().approx_eq(&0.0));
         assert!(down.angle_from_x_axis().get().approx_eq(&FRAC_PI_2));
         assert!(up.angle_from_x_axis().get().approx_eq(&-FRAC_PI_2));
+
+        let left: Vec2 = vec2(-3.0, 0.0);
+        let left_down: Vec2 = vec2(-1.0, 1.0);
+        let left_up: Vec2 = vec2(-1.0, -1.0);
+
+        assert!(left.angle_from_x_axis().get().approx_eq(&PI));
+        assert!(vec2::<f32, UnknownUnit>(-3.0, -0.0).angle_from_x_axis().get().approx_eq(&PI));
+        // The approximation is less precise away from the axes.
+        assert!(left_down.angle_from_x_axis().get().approx_eq_eps(&(3.0 * FRAC_PI_4), &0.001));
+        assert!(left_up.angle_from_x_axis().get().approx_eq_eps(&(-3.0 * FRAC_PI_4), &0.001));
+    }
+
+    #[test]
+    pub fn test_signed_area() {
+        use super::{signed_area, is_clockwise};
+        use point::{Point2D, point2};
+
+        let ccw: [Point2D<f32>; 4] = [
+            point2(0.0, 0.0),
+            point2(4.0, 0.0),
+            point2(4.0, 3.0),
+            point2(0.0, 3.0),
+        ];
+        let mut cw = ccw;
+        cw.reverse();
+
+        assert_eq!(signed_area(&ccw), 12.0);
+        assert_eq!(signed_area(&cw), -12.0);
+        assert!(!is_clockwise(&ccw));
+        assert!(is_clockwise(&cw));
+
+        let triangle: [Point2D<f32>; 3] = [point2(1.0, 1.0), point2(3.0, 1.0), point2(1.0, 5.0)];
+        assert_eq!(signed_area(&triangle), 4.0);
+
+        assert_eq!(signed_area(&ccw[..2]), 0.0);
+        let empty: [Point2D<f32>; 0] = [];
+        assert!(!is_clockwise(&empty));
+    }
+
+    #[test]
+    pub fn test_signed_area_integer() {
+        use super::{signed_area, is_clockwise};
+        use point::{Point2D, point2};
+
+        // The area of these unit triangles truncates to zero, but the winding is exact.
+        let cw: [Point2D<i32>; 3] = [point2(0, 0), point2(0, 1), point2(1, 0)];
+        let ccw: [Point2D<i32>; 3] = [point2(0, 0), point2(1, 0), point2(0, 1)];
+        assert_eq!(signed_area(&cw), 0);
+        assert_eq!(signed_area(&ccw), 0);
+        assert!(is_clockwise(&cw));
+        assert!(!is_clockwise(&ccw));
+
+        let square: [Point2D<i32>; 4] = [point2(0, 0), point2(0, 2), point2(2, 2), point2(2, 0)];
+        assert_eq!(signed_area(&square), -4);
+        assert!(is_clockwise(&square));
+    }
+
+    #[test]
+    pub fn test_polar() {
+        use Angle;
+        use approxeq::ApproxEq;
+        use std::f32::consts::FRAC_PI_2;
+
+        let v: Vec2 = vec2(0.0, 2.0);
+        let (length, angle) = v.to_polar();
+        assert!(length.approx_eq(&2.0));
+        assert!(angle.get().approx_eq(&FRAC_PI_2));
+
+        for &v in &[vec2(3.0, 4.0), vec2(-1.5, 0.5), vec2(-2.0, -7.0), vec2(1.0, 0.0)] {
+            let v: Vec2 = v;
+            let (length, angle) = v.to_polar();
+            assert!(Vec2::from_angle_and_length(angle, length).approx_eq(&v));
+        }
+
+        let v: Vec2 = Vec2::from_angle_and_length(Angle::radians(FRAC_PI_2), 3.0);
+        assert!(v.approx_eq(&vec2(0.0, 3.0)));
+
+        let (length, angle) = Vec2::zero().to_polar();
+        assert_eq!(length, 0.0);
+        assert_eq!(angle.get(), 0.0);
+
+        // Vectors along the negative x axis are at PI, even with a negative zero y.
+        let v: Vec2 = vec2(-1.0, -0.0);
+        let (_, angle) = v.to_polar();
+        assert_eq!(angle.get(), ::std::f32::consts::PI);
+    }
+
+    #[test]
+    pub fn test_approx_eq_relative() {
+        // Negative components count by their magnitude.
+        let v1: Vec2 = vec2(-1.0e6, 0.0);
+        let v2: Vec2 = vec2(-1.0e6, 0.5);
+        assert!(v1.approx_eq_relative(&v2, 1.0e-6));
+
+        // The magnitude of `other` counts as well.
+        let zero: Vec2 = vec2(0.0, 0.0);
+        assert!(!zero.approx_eq_relative(&vec2(1.0e-3, 0.0), 0.5));
+        assert!(zero.approx_eq_relative(&vec2(1.0e-3, 0.0), 1.0));
+    }
+
+    #[test]
+    pub fn test_is_finite_and_approx_zero() {
+        assert!(Vec2::new(1.0, -2.0).is_finite());
+        assert!(!Vec2::new(f32::NAN, 0.0).is_finite());
+        assert!(!Vec2::new(0.0, f32::INFINITY).is_finite());
+        assert!(!(Vec2::new(1.0, 0.0) / 0.0).is_finite());
+
+        assert!(Vec2::zero().approx_zero());
+        assert!(Vec2::new(1.0e-8, -1.0e-8).approx_zero());
+        assert!(!Vec2::new(0.0, 0.1).approx_zero());
+        assert!(!Vec2::new(f32::NAN, 0.0).approx_zero());
+    }
+
+    #[test]
+    pub fn test_perpendicular() {
+        let v = Vec2::new(3.0, 1.0);
+        assert_eq!(v.perpendicular(), Vec2::new(-1.0, 3.0));
+        assert_eq!(v.perpendicular_cw(), Vec2::new(1.0, -3.0));
+        assert_eq!(v.perpendicular().dot(v), 0.0);
+        // Counter-clockwise: the cross product is positive.
+        assert!(v.cross(v.perpendicular()) > 0.0);
+        assert_eq!(v.perpendicular().perpendicular_cw(), v);
+        assert_eq!(v.perpendicular().perpendicular(), -v);
+
+        let i: Vector2D<i32> = vec2(2, -5);
+        assert_eq!(i.perpendicular(), vec2(5, 2));
+        assert_eq!(i.perpendicular_cw(), vec2(-5, -2));
     }
 }
 
@@ -930,6 +1795,19 @@ mod vector3d {
     use super::{Vector3D, vec2, vec3};
     type Vec3 = Vector3D<f32>;
 
+    #[test]
+    pub fn test_length_typed() {
+        use super::TypedVector3D;
+        use length::Length;
+        struct Mm;
+
+        let v: TypedVector3D<f32, Mm> = vec3(2.0, 3.0, 6.0);
+        let length: Length<f32, Mm> = v.length_typed();
+        let square_length: Length<f32, Mm> = v.square_length_typed();
+        assert_eq!(length.get(), 7.0);
+        assert_eq!(square_length.get(), 49.0);
+    }
+
     #[test]
     pub fn test_dot() {
         let p1: Vec3 = vec3(7.0, 21.0, 32.0);
@@ -982,4 +1860,90 @@ mod vector3d {
         assert_eq!(p.xz(), vec2(1, 3));
         assert_eq!(p.yz(), vec2(2, 3));
     }
+
+    #[test]
+    pub fn test_approx_eq_relative() {
+        // A difference in z alone is enough to tell the vectors apart.
+        let v1: Vec3 = vec3(1.0e6, 0.0, 1.0);
+        let v2: Vec3 = vec3(1.0e6, 0.0, 3.0);
+        assert!(!v1.approx_eq_relative(&v2, 1.0e-6));
+        assert!(v1.approx_eq_relative(&v2, 1.0e-5));
+    }
+
+    #[test]
+    pub fn test_is_finite_and_approx_zero() {
+        assert!(Vec3::new(1.0, -2.0, 3.0).is_finite());
+        assert!(!Vec3::new(0.0, 0.0, f32::NAN).is_finite());
+        assert!(!Vec3::new(f32::NEG_INFINITY, 0.0, 0.0).is_finite());
+
+        assert!(Vec3::zero().approx_zero());
+        assert!(!Vec3::new(0.0, 0.0, 0.1).approx_zero());
+    }
+
+    #[test]
+    pub fn test_index() {
+        let mut v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!((v[0], v[1], v[2]), (1.0, 2.0, 3.0));
+        v[2] = 5.0;
+        v[0] += 1.0;
+        assert_eq!(v, Vec3::new(2.0, 2.0, 5.0));
+        assert_eq!(v.iter().collect::<Vec<_>>(), vec![2.0, 2.0, 5.0]);
+
+        // Axis-generic code: find the axis with the largest component.
+        let axis = (0..3).fold(0, |best, i| if v[i] > v[best] { i } else { best });
+        assert_eq!(axis, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn test_index_out_of_range() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let _ = v[3];
+    }
+}
+
+#[cfg(test)]
+mod vector4d {
+    use super::{Vector3D, Vector4D, vec3, vec4};
+    type Vec4 = Vector4D<f32>;
+
+    #[test]
+    pub fn test_arithmetic() {
+        let v1: Vec4 = vec4(1.0, 2.0, 3.0, 4.0);
+        let v2: Vec4 = vec4(4.0, 3.0, 2.0, 1.0);
+        assert_eq!(v1 + v2, vec4(5.0, 5.0, 5.0, 5.0));
+        assert_eq!(v1 - v2, vec4(-3.0, -1.0, 1.0, 3.0));
+        assert_eq!(-v1, vec4(-1.0, -2.0, -3.0, -4.0));
+        assert_eq!(v1 * 2.0, vec4(2.0, 4.0, 6.0, 8.0));
+        assert_eq!(v1 / 2.0, vec4(0.5, 1.0, 1.5, 2.0));
+        assert_eq!(v1.min(v2), vec4(1.0, 2.0, 2.0, 1.0));
+        assert_eq!(v1.max(v2), vec4(4.0, 3.0, 3.0, 4.0));
+        assert_eq!(v1.lerp(v2, 0.5), vec4(2.5, 2.5, 2.5, 2.5));
+
+        let mut v = v1;
+        v += v2;
+        v *= 2.0;
+        assert_eq!(v, vec4(10.0, 10.0, 10.0, 10.0));
+    }
+
+    #[test]
+    pub fn test_dot_and_length() {
+        let v1: Vec4 = vec4(1.0, 2.0, 3.0, 4.0);
+        let v2: Vec4 = vec4(4.0, -3.0, 2.0, 1.0);
+        assert_eq!(v1.dot(v2), 8.0);
+        assert_eq!(vec4::<f32, ::UnknownUnit>(1.0, 1.0, 1.0, 1.0).length(), 2.0);
+        assert_eq!(vec4::<f32, ::UnknownUnit>(0.0, 0.0, 0.0, 4.0).normalize(), vec4(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    pub fn test_conversions() {
+        let v: Vec4 = vec4(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.to_3d(), vec3(1.0, 2.0, 3.0));
+        let v3: Vector3D<f32> = vec3(1.0, 2.0, 3.0);
+        assert_eq!(v3.extend(4.0), v);
+        assert_eq!(v.to_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(Vec4::from([1.0, 2.0, 3.0, 4.0]), v);
+        assert_eq!(v.cast::<i32>(), Some(vec4(1, 2, 3, 4)));
+        assert_eq!(Vec4::from_le_bytes(v.to_le_bytes()), v);
+    }
 }